@@ -46,7 +46,7 @@ impl DisjointUsizeSet {
         return self.nodes.len() - 1;
     }
 
-    fn find_root(&mut self, key: usize) -> Option<usize> {
+    pub(crate) fn find_root(&mut self, key: usize) -> Option<usize> {
         let mut current = key;
         while current != self.nodes.get(current)?.parent {
             self.nodes.get_mut(current)?.parent =
@@ -0,0 +1,242 @@
+use super::{grid, grid_point, lattice, neighborhood};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    hash::{Hash, Hasher},
+};
+
+////////////
+/// Cellular Automaton
+///
+/// repeatedly applies a rule to every cell of a grid or lattice, where each
+/// cell's next value depends only on its current value and its neighbors
+////////////
+
+pub trait CellularAutomaton {
+    fn step(&mut self);
+
+    fn generation(&self) -> usize;
+
+    // only used when jumping over whole cycles, the state is left untouched
+    fn set_generation(&mut self, generation: usize);
+
+    fn state_hash(&self) -> u64;
+
+    fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    // steps until the given generation, skipping ahead once the state repeats,
+    // returns the length of the cycle if one was found on the way
+    fn jump_to(&mut self, generation: usize) -> Option<usize> {
+        let mut seen = HashMap::new();
+        while self.generation() < generation {
+            if let Some(first_seen) = seen.insert(self.state_hash(), self.generation()) {
+                let period = self.generation() - first_seen;
+                self.run((generation - self.generation()) % period);
+                self.set_generation(generation);
+                return Some(period);
+            }
+            self.step();
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    Bounded,
+    Wrapping,
+}
+
+////////////
+/// Grid Automaton
+///
+/// double buffered automaton over a fixed size grid, cells off the edge
+/// are either missing from the neighbor list or wrap around to the other side
+////////////
+
+pub struct GridAutomaton<T, F: Fn(&T, &[&T]) -> T> {
+    current: grid::Grid<T>,
+    next: grid::Grid<T>,
+    deltas: Vec<grid_point::GridPointDelta<isize>>,
+    edges: Edges,
+    rule: F,
+    generation: usize,
+}
+
+impl<T: Clone, F: Fn(&T, &[&T]) -> T> GridAutomaton<T, F> {
+    pub fn new(
+        grid: grid::Grid<T>,
+        neighborhood: neighborhood::Neighborhood,
+        edges: Edges,
+        rule: F,
+    ) -> Self {
+        GridAutomaton {
+            next: grid.clone(),
+            current: grid,
+            deltas: neighborhood.into_iter().collect(),
+            edges,
+            rule,
+            generation: 0,
+        }
+    }
+}
+
+impl<T, F: Fn(&T, &[&T]) -> T> GridAutomaton<T, F> {
+    pub fn state(&self) -> &grid::Grid<T> {
+        &self.current
+    }
+
+    pub fn into_state(self) -> grid::Grid<T> {
+        self.current
+    }
+}
+
+impl<T: Hash, F: Fn(&T, &[&T]) -> T> CellularAutomaton for GridAutomaton<T, F> {
+    fn step(&mut self) {
+        let dimensions = self.current.dimensions();
+        let mut neighbors = Vec::with_capacity(self.deltas.len());
+        for point in self.current.iter_points() {
+            neighbors.clear();
+            for delta in &self.deltas {
+                match self.edges {
+                    Edges::Bounded => {
+                        if let Some(neighbor) = point.add_checked(*delta, &dimensions) {
+                            neighbors.push(&self.current[neighbor]);
+                        }
+                    }
+                    Edges::Wrapping => {
+                        neighbors.push(self.current.get_tiled(grid_point::GridPoint::new(
+                            point.row as isize + delta.row_delta,
+                            point.col as isize + delta.col_delta,
+                        )))
+                    }
+                }
+            }
+            self.next[point] = (self.rule)(&self.current[point], &neighbors);
+        }
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+    }
+
+    fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.generation = generation;
+    }
+
+    fn state_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for point in self.current.iter_points() {
+            self.current[point].hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
+
+////////////
+/// Lattice Automaton
+///
+/// automaton over an infinite plane, only cells which differ from the background
+/// are stored, and only cells next to a change are reconsidered each step.
+/// the rule must map a background cell surrounded by background to background
+////////////
+
+pub struct LatticeAutomaton<T, F: Fn(&T, &[&T]) -> T> {
+    current: lattice::Lattice<T>,
+    background: T,
+    deltas: Vec<grid_point::GridPointDelta<isize>>,
+    frontier: HashSet<grid_point::GridPoint<isize>>,
+    rule: F,
+    generation: usize,
+}
+
+impl<T: Clone + PartialEq, F: Fn(&T, &[&T]) -> T> LatticeAutomaton<T, F> {
+    pub fn new(
+        lattice: lattice::Lattice<T>,
+        background: T,
+        neighborhood: neighborhood::Neighborhood,
+        rule: F,
+    ) -> Self {
+        let mut automaton = LatticeAutomaton {
+            current: lattice::Lattice::empty(),
+            background,
+            deltas: neighborhood.into_iter().collect(),
+            frontier: HashSet::new(),
+            rule,
+            generation: 0,
+        };
+        for (point, value) in lattice {
+            automaton.set(point, value);
+        }
+        automaton
+    }
+}
+
+impl<T: PartialEq, F: Fn(&T, &[&T]) -> T> LatticeAutomaton<T, F> {
+    pub fn state(&self) -> &lattice::Lattice<T> {
+        &self.current
+    }
+
+    pub fn into_state(self) -> lattice::Lattice<T> {
+        self.current
+    }
+
+    pub fn get(&self, point: grid_point::GridPoint<isize>) -> &T {
+        self.current.get(point).unwrap_or(&self.background)
+    }
+
+    fn set(&mut self, point: grid_point::GridPoint<isize>, value: T) {
+        self.frontier.insert(point);
+        self.frontier
+            .extend(self.deltas.iter().map(|delta| point - *delta));
+        if value == self.background {
+            self.current.remove(point);
+        } else {
+            self.current.set(point, value);
+        }
+    }
+}
+
+impl<T: PartialEq + Hash, F: Fn(&T, &[&T]) -> T> CellularAutomaton for LatticeAutomaton<T, F> {
+    fn step(&mut self) {
+        let mut neighbors = Vec::with_capacity(self.deltas.len());
+        let mut changes = Vec::new();
+        for point in std::mem::take(&mut self.frontier) {
+            neighbors.clear();
+            neighbors.extend(self.deltas.iter().map(|delta| self.get(point + *delta)));
+            let value = (self.rule)(self.get(point), &neighbors);
+            if value != *self.get(point) {
+                changes.push((point, value));
+            }
+        }
+        for (point, value) in changes {
+            self.set(point, value);
+        }
+        self.generation += 1;
+    }
+
+    fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.generation = generation;
+    }
+
+    // combined so that the hash doesn't depend on the order cells are stored in
+    fn state_hash(&self) -> u64 {
+        self.current
+            .iter()
+            .map(|entry| {
+                let mut hasher = DefaultHasher::new();
+                entry.hash(&mut hasher);
+                hasher.finish()
+            })
+            .fold(0, u64::wrapping_add)
+    }
+}
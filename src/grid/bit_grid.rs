@@ -0,0 +1,331 @@
+use super::{error, grid, grid_dimension, grid_point, iterators, neighborhood};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+////////////
+/// Bit Grid
+///
+/// grid of booleans packed into u64 words, each row starting on a new word,
+/// so that whole rows can be combined and shifted a word at a time.
+/// bits past the last column are always kept clear
+////////////
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    dimensions: grid_dimension::GridDimensions<usize>,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        let words_per_row = cols.div_ceil(64);
+        BitGrid {
+            dimensions: grid_dimension::GridDimensions::new(0, rows, 0, cols),
+            words_per_row,
+            words: vec![0; rows * words_per_row],
+        }
+    }
+
+    pub fn cols(&self) -> usize {
+        self.dimensions.cols()
+    }
+
+    pub fn rows(&self) -> usize {
+        self.dimensions.rows()
+    }
+
+    pub fn dimensions(&self) -> grid_dimension::GridDimensions<usize> {
+        self.dimensions
+    }
+
+    pub fn iter_points(&self) -> iterators::GridDimensionIterator<usize> {
+        self.dimensions.all_contained_points()
+    }
+
+    pub fn get(&self, point: grid_point::GridPoint<usize>) -> error::IndexResult<bool, usize> {
+        if !self.dimensions.contains(&point) {
+            return error::IndexOutOfBoundsError::err(self.dimensions, point);
+        }
+        let (word, bit) = self.word_and_bit(point);
+        Ok(self.words[word] & bit != 0)
+    }
+
+    pub fn set(
+        &mut self,
+        point: grid_point::GridPoint<usize>,
+        value: bool,
+    ) -> error::IndexResult<(), usize> {
+        if !self.dimensions.contains(&point) {
+            return error::IndexOutOfBoundsError::err(self.dimensions, point);
+        }
+        let (word, bit) = self.word_and_bit(point);
+        if value {
+            self.words[word] |= bit;
+        } else {
+            self.words[word] &= !bit;
+        }
+        Ok(())
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn row_count_ones(&self, row: usize) -> usize {
+        self.row(row)
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    // only the points which are set, in reading order
+    pub fn iter_ones(&self) -> impl Iterator<Item = grid_point::GridPoint<usize>> + '_ {
+        self.words.iter().enumerate().flat_map(move |(idx, word)| {
+            let row = idx / self.words_per_row.max(1);
+            let col_offset = (idx % self.words_per_row.max(1)) * 64;
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(grid_point::GridPoint::new(row, col_offset + bit))
+            })
+        })
+    }
+
+    pub fn to_grid(&self) -> grid::Grid<bool> {
+        let mut grid = grid::Grid::init(false, self.rows(), self.cols());
+        for point in self.iter_ones() {
+            grid[point] = true;
+        }
+        grid
+    }
+
+    fn word_and_bit(&self, point: grid_point::GridPoint<usize>) -> (usize, u64) {
+        (
+            point.row * self.words_per_row + point.col / 64,
+            1 << (point.col % 64),
+        )
+    }
+
+    fn row(&self, row: usize) -> &[u64] {
+        &self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    fn last_word_mask(&self) -> u64 {
+        match self.cols() % 64 {
+            0 => !0,
+            bits => (1 << bits) - 1,
+        }
+    }
+
+    fn clear_past_last_col(&mut self) {
+        let mask = self.last_word_mask();
+        for row in self.words.chunks_mut(self.words_per_row.max(1)) {
+            if let Some(last) = row.last_mut() {
+                *last &= mask;
+            }
+        }
+    }
+}
+
+impl From<&grid::Grid<bool>> for BitGrid {
+    fn from(grid: &grid::Grid<bool>) -> Self {
+        let mut bit_grid = BitGrid::new(grid.rows(), grid.cols());
+        for point in grid.iter_points().filter(|point| grid[*point]) {
+            let (word, bit) = bit_grid.word_and_bit(point);
+            bit_grid.words[word] |= bit;
+        }
+        bit_grid
+    }
+}
+
+////////////
+/// Bit Grid shifting
+///
+/// moves every cell by a delta, cells moved off the grid are lost
+/// and the cells left behind are cleared
+////////////
+
+impl BitGrid {
+    pub fn shift(&mut self, delta: grid_point::GridPointDelta<isize>) {
+        let rows = self.rows();
+        let words_per_row = self.words_per_row;
+        let row_shift = delta.row_delta.unsigned_abs().min(rows);
+        if delta.row_delta > 0 {
+            self.words.copy_within(
+                ..(rows - row_shift) * words_per_row,
+                row_shift * words_per_row,
+            );
+            self.words[..row_shift * words_per_row].fill(0);
+        } else if delta.row_delta < 0 {
+            self.words.copy_within(row_shift * words_per_row.., 0);
+            self.words[(rows - row_shift) * words_per_row..].fill(0);
+        }
+
+        let col_shift = delta.col_delta.unsigned_abs();
+        if col_shift == 0 || words_per_row == 0 {
+            return;
+        }
+        for row in self.words.chunks_mut(words_per_row) {
+            if delta.col_delta > 0 {
+                shift_row_up(row, col_shift);
+            } else {
+                shift_row_down(row, col_shift);
+            }
+        }
+        self.clear_past_last_col();
+    }
+
+    pub fn shifted(&self, delta: grid_point::GridPointDelta<isize>) -> Self {
+        let mut shifted = self.clone();
+        shifted.shift(delta);
+        shifted
+    }
+}
+
+// moves bit i of the row to bit i + n
+fn shift_row_up(row: &mut [u64], n: usize) {
+    let (word_shift, bit_shift) = (n / 64, n % 64);
+    for idx in (0..row.len()).rev() {
+        row[idx] = match idx.checked_sub(word_shift) {
+            None => 0,
+            Some(src) if bit_shift == 0 => row[src],
+            Some(0) => row[0] << bit_shift,
+            Some(src) => (row[src] << bit_shift) | (row[src - 1] >> (64 - bit_shift)),
+        };
+    }
+}
+
+// moves bit i of the row to bit i - n
+fn shift_row_down(row: &mut [u64], n: usize) {
+    let (word_shift, bit_shift) = (n / 64, n % 64);
+    for idx in 0..row.len() {
+        let src = idx + word_shift;
+        row[idx] = if src >= row.len() {
+            0
+        } else if bit_shift == 0 {
+            row[src]
+        } else if src + 1 == row.len() {
+            row[src] >> bit_shift
+        } else {
+            (row[src] >> bit_shift) | (row[src + 1] << (64 - bit_shift))
+        };
+    }
+}
+
+////////////
+/// Neighbor Counts
+///
+/// per cell count of set neighbors, stored bit sliced: plane i holds bit i
+/// of every cell's count, so the counts are built with word wide adders
+////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NeighborCounts {
+    dimensions: grid_dimension::GridDimensions<usize>,
+    planes: Vec<BitGrid>,
+}
+
+impl BitGrid {
+    pub fn neighbor_counts(&self, neighborhood: neighborhood::Neighborhood) -> NeighborCounts {
+        let deltas = neighborhood.deltas().to_vec();
+        let plane_count = (usize::BITS - deltas.len().leading_zeros()) as usize;
+        let mut planes = vec![BitGrid::new(self.rows(), self.cols()); plane_count];
+        for delta in deltas {
+            // the neighbor at p + delta lands on p when shifted by -delta
+            let mut carry = self.shifted(-delta);
+            for plane in planes.iter_mut() {
+                let next_carry = &*plane & &carry;
+                *plane ^= &carry;
+                carry = next_carry;
+            }
+        }
+        NeighborCounts {
+            dimensions: self.dimensions,
+            planes,
+        }
+    }
+}
+
+impl NeighborCounts {
+    pub fn get(&self, point: grid_point::GridPoint<usize>) -> error::IndexResult<usize, usize> {
+        if !self.dimensions.contains(&point) {
+            return error::IndexOutOfBoundsError::err(self.dimensions, point);
+        }
+        self.planes
+            .iter()
+            .enumerate()
+            .try_fold(0, |count, (bit, plane)| {
+                Ok(count | ((plane.get(point)? as usize) << bit))
+            })
+    }
+
+    // mask of the cells with exactly count neighbors set
+    pub fn equal_to(&self, count: usize) -> BitGrid {
+        let empty = BitGrid::new(self.dimensions.rows(), self.dimensions.cols());
+        if count >> self.planes.len() != 0 {
+            return empty;
+        }
+        let mut mask = !&empty;
+        for (bit, plane) in self.planes.iter().enumerate() {
+            if count >> bit & 1 == 1 {
+                mask &= plane;
+            } else {
+                mask &= &!plane;
+            }
+        }
+        mask
+    }
+}
+
+////////////
+/// Bit Grid operators
+///
+/// both sides must have the same dimensions
+////////////
+
+macro_rules! bit_grid_op_impl {
+    ($op_assign:ident, $op_assign_fn:ident, $op:ident, $op_fn:ident) => {
+        impl $op_assign<&BitGrid> for BitGrid {
+            fn $op_assign_fn(&mut self, rhs: &BitGrid) {
+                assert_eq!(self.dimensions, rhs.dimensions);
+                for (word, rhs) in self.words.iter_mut().zip(rhs.words.iter()) {
+                    word.$op_assign_fn(rhs);
+                }
+            }
+        }
+
+        impl $op<&BitGrid> for &BitGrid {
+            type Output = BitGrid;
+
+            fn $op_fn(self, rhs: &BitGrid) -> Self::Output {
+                let mut result = self.clone();
+                result.$op_assign_fn(rhs);
+                result
+            }
+        }
+    };
+}
+
+bit_grid_op_impl!(BitAndAssign, bitand_assign, BitAnd, bitand);
+bit_grid_op_impl!(BitOrAssign, bitor_assign, BitOr, bitor);
+bit_grid_op_impl!(BitXorAssign, bitxor_assign, BitXor, bitxor);
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> Self::Output {
+        let mut result = self.clone();
+        for word in result.words.iter_mut() {
+            *word = !*word;
+        }
+        result.clear_past_last_col();
+        result
+    }
+}
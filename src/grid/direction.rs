@@ -21,7 +21,27 @@ where
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Back,
+}
+
+impl Turn {
+    // L, R or B
+    pub fn of_char(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Self::Left),
+            'R' => Some(Self::Right),
+            'B' => Some(Self::Back),
+            _ => None,
+        }
+    }
+}
+
 impl Direction {
+    // clockwise starting from North, matching the order of PLUS_ADJACENT
     pub fn all() -> [Self; 4] {
         [Self::North, Self::East, Self::South, Self::West]
     }
@@ -43,6 +63,69 @@ impl Direction {
             Self::West => Self::North,
         }
     }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.rotate_left(),
+            Turn::Right => self.rotate_right(),
+            Turn::Back => -self,
+        }
+    }
+
+    // only single steps have a direction
+    pub fn from_delta(delta: grid_point::GridPointDelta<isize>) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|direction| grid_point::GridPointDelta::from(*direction) == delta)
+    }
+
+    // ^>v<
+    pub fn of_arrow(c: char) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|direction| direction.arrow() == c)
+    }
+
+    // NESW
+    pub fn of_compass(c: char) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|direction| direction.compass() == c)
+    }
+
+    // UDLR, which overlaps with Turn::of_char so it gets its own constructor
+    pub fn of_udlr(c: char) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|direction| direction.udlr() == c)
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Self::North => '^',
+            Self::East => '>',
+            Self::South => 'v',
+            Self::West => '<',
+        }
+    }
+
+    pub fn compass(self) -> char {
+        match self {
+            Self::North => 'N',
+            Self::East => 'E',
+            Self::South => 'S',
+            Self::West => 'W',
+        }
+    }
+
+    pub fn udlr(self) -> char {
+        match self {
+            Self::North => 'U',
+            Self::East => 'R',
+            Self::South => 'D',
+            Self::West => 'L',
+        }
+    }
 }
 
 impl From<Direction> for grid_point::GridPointDelta<isize> {
@@ -68,3 +151,124 @@ impl Neg for Direction {
         }
     }
 }
+
+////////////
+/// Direction8
+///
+/// the four cardinal directions along with the diagonals between them
+////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl<T> Mul<T> for Direction8
+where
+    grid_point::GridPointDelta<T>: From<Direction8>,
+    T: Mul<T, Output = T> + Clone,
+{
+    type Output = grid_point::GridPointDelta<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        grid_point::GridPointDelta::<T>::from(self) * rhs
+    }
+}
+
+impl Direction8 {
+    // clockwise starting from North, matching the order of ADJACENT
+    pub fn all() -> [Self; 8] {
+        [
+            Self::North,
+            Self::NorthEast,
+            Self::East,
+            Self::SouthEast,
+            Self::South,
+            Self::SouthWest,
+            Self::West,
+            Self::NorthWest,
+        ]
+    }
+
+    fn index(self) -> usize {
+        Self::all()
+            .into_iter()
+            .position(|direction| direction == self)
+            .unwrap()
+    }
+
+    // an eighth of a turn
+    pub fn rotate_left(self) -> Self {
+        Self::all()[(self.index() + 7) % 8]
+    }
+
+    // an eighth of a turn
+    pub fn rotate_right(self) -> Self {
+        Self::all()[(self.index() + 1) % 8]
+    }
+
+    // a quarter of a turn, as for Direction
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.rotate_left().rotate_left(),
+            Turn::Right => self.rotate_right().rotate_right(),
+            Turn::Back => -self,
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    pub fn from_delta(delta: grid_point::GridPointDelta<isize>) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|direction| grid_point::GridPointDelta::from(*direction) == delta)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::North => Self::North,
+            Direction::East => Self::East,
+            Direction::South => Self::South,
+            Direction::West => Self::West,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    fn try_from(value: Direction8) -> Result<Self, Self::Error> {
+        match value {
+            Direction8::North => Ok(Self::North),
+            Direction8::East => Ok(Self::East),
+            Direction8::South => Ok(Self::South),
+            Direction8::West => Ok(Self::West),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+impl From<Direction8> for grid_point::GridPointDelta<isize> {
+    fn from(value: Direction8) -> Self {
+        constants::ADJACENT[value.index()]
+    }
+}
+
+impl Neg for Direction8 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::all()[(self.index() + 4) % 8]
+    }
+}
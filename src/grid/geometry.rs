@@ -0,0 +1,99 @@
+use super::{direction, grid_point};
+use crate::number_theory::gcd;
+use num::rational::Ratio;
+
+////////////
+/// Polygon
+///
+/// closed polygon on the integer lattice, the last vertex joins back up to the
+/// first. all the sums are done in i128, which large dig plans need
+////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<grid_point::GridPoint<isize>>,
+}
+
+impl Polygon {
+    pub fn new<I: IntoIterator<Item = grid_point::GridPoint<isize>>>(vertices: I) -> Self {
+        Polygon {
+            vertices: vertices.into_iter().collect(),
+        }
+    }
+
+    // the corners visited following each move from start, the final
+    // move should lead back to start but the polygon is closed either way
+    pub fn of_path<I: IntoIterator<Item = (direction::Direction, isize)>>(
+        start: grid_point::GridPoint<isize>,
+        moves: I,
+    ) -> Self {
+        let mut vertices = vec![start];
+        let mut current = start;
+        for (direction, length) in moves {
+            current += direction * length;
+            vertices.push(current);
+        }
+        if vertices.len() > 1 && vertices.last() == Some(&start) {
+            vertices.pop();
+        }
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[grid_point::GridPoint<isize>] {
+        &self.vertices
+    }
+
+    fn edges(
+        &self,
+    ) -> impl Iterator<Item = (grid_point::GridPoint<isize>, grid_point::GridPoint<isize>)> + '_
+    {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    // shoelace formula, positive when the vertices go clockwise on screen
+    // (rows increasing downwards), so this is always a whole number
+    pub fn signed_doubled_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| a.col as i128 * b.row as i128 - b.col as i128 * a.row as i128)
+            .sum()
+    }
+
+    pub fn doubled_area(&self) -> i128 {
+        self.signed_doubled_area().abs()
+    }
+
+    pub fn area(&self) -> Ratio<i128> {
+        Ratio::new(self.doubled_area(), 2)
+    }
+
+    // lattice points lying on the edges, which for axis aligned edges
+    // is the same as the total length of the boundary
+    pub fn boundary_points(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| {
+                gcd(
+                    (b.row as i128 - a.row as i128).abs(),
+                    (b.col as i128 - a.col as i128).abs(),
+                )
+            })
+            .sum()
+    }
+
+    // lattice points strictly inside, by pick's theorem A = I + B / 2 - 1,
+    // None when there are fewer than 3 vertices or no area for it to apply to
+    pub fn interior_points(&self) -> Option<i128> {
+        let doubled_area = self.doubled_area();
+        if self.vertices.len() < 3 || doubled_area == 0 {
+            return None;
+        }
+        Some((doubled_area - self.boundary_points() + 2) / 2)
+    }
+
+    // lattice points inside or on the boundary, None when interior_points is
+    pub fn enclosed_points(&self) -> Option<i128> {
+        Some(self.interior_points()? + self.boundary_points())
+    }
+}
@@ -1,5 +1,9 @@
-use super::{constants, error, grid_dimension, grid_point, iterators};
+use super::{
+    block, constants, direction, error, grid_dimension, grid_point, iterators, neighborhood,
+};
+use crate::disjoint_set::{DisjointSet, DisjointUsizeSet};
 use std::{
+    collections::VecDeque,
     fmt::{Debug, Display},
     ops::{Index, IndexMut},
 };
@@ -182,6 +186,454 @@ impl<T> Grid<T> {
     }
 }
 
+////////////
+/// Grid neighbors
+///
+/// iterating over the points adjacent to a point, either stopping at the
+/// edges, wrapping around them, or treating the grid as infinitely tiled
+////////////
+
+impl<T> Grid<T> {
+    pub fn neighbors(
+        &self,
+        point: grid_point::GridPoint<usize>,
+        neighborhood: neighborhood::Neighborhood,
+    ) -> impl Iterator<Item = (grid_point::GridPoint<usize>, &T)> + '_ {
+        neighborhood.into_iter().filter_map(move |delta| {
+            let neighbor = point.add_checked(delta, &self.dimensions)?;
+            Some((neighbor, &self[neighbor]))
+        })
+    }
+
+    pub fn neighbors_wrapping(
+        &self,
+        point: grid_point::GridPoint<usize>,
+        neighborhood: neighborhood::Neighborhood,
+    ) -> impl Iterator<Item = (grid_point::GridPoint<usize>, &T)> + '_ {
+        neighborhood.into_iter().map(move |delta| {
+            let neighbor = self.wrap(grid_point::GridPoint::new(
+                point.row as isize + delta.row_delta,
+                point.col as isize + delta.col_delta,
+            ));
+            (neighbor, &self[neighbor])
+        })
+    }
+
+    pub fn neighbors_tiled(
+        &self,
+        point: grid_point::GridPoint<isize>,
+        neighborhood: neighborhood::Neighborhood,
+    ) -> impl Iterator<Item = (grid_point::GridPoint<isize>, &T)> + '_ {
+        neighborhood.into_iter().map(move |delta| {
+            let neighbor = point + delta;
+            (neighbor, self.get_tiled(neighbor))
+        })
+    }
+
+    // value at a point in the infinite plane made by repeating the grid
+    pub fn get_tiled(&self, point: grid_point::GridPoint<isize>) -> &T {
+        &self[self.wrap(point)]
+    }
+
+    fn wrap(&self, point: grid_point::GridPoint<isize>) -> grid_point::GridPoint<usize> {
+        grid_point::GridPoint::new(
+            point.row.rem_euclid(self.rows() as isize) as usize,
+            point.col.rem_euclid(self.cols() as isize) as usize,
+        )
+    }
+}
+
+////////////
+/// Grid regions
+///
+/// flood fill and connected component labelling, for puzzles
+/// which split the grid up into regions of like cells
+////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    pub area: usize,
+    pub perimeter: usize,
+    pub sides: usize,
+}
+
+impl<T> Grid<T> {
+    // all points reachable from start by plus adjacent steps through cells matching predicate
+    pub fn flood_fill<F: Fn(&T) -> bool>(
+        &self,
+        start: grid_point::GridPoint<usize>,
+        predicate: F,
+    ) -> block::Block<usize> {
+        let mut region = block::Block::empty();
+        if !self.get(start).is_ok_and(&predicate) {
+            return region;
+        }
+        let mut seen = Grid::init(false, self.rows(), self.cols());
+        let mut frontier = VecDeque::from([start]);
+        seen[start] = true;
+        while let Some(point) = frontier.pop_front() {
+            region.add_point(point);
+            for delta in constants::PLUS_ADJACENT {
+                if let Some(next) = point.add_checked(delta, &self.dimensions) {
+                    if !seen[next] && predicate(&self[next]) {
+                        seen[next] = true;
+                        frontier.push_back(next);
+                    }
+                }
+            }
+        }
+        region
+    }
+
+    // label every cell with the index of its region, where plus adjacent cells are in
+    // the same region when same_region holds, labels are assigned in reading order
+    pub fn connected_components<F: Fn(&T, &T) -> bool>(
+        &self,
+        same_region: F,
+    ) -> (Grid<usize>, Vec<Region>) {
+        let mut sets = DisjointUsizeSet::init(self.grid.len());
+        for point in self.iter_points() {
+            for delta in [constants::EAST, constants::SOUTH] {
+                if let Some(next) = point.add_checked(delta, &self.dimensions) {
+                    if same_region(&self[point], &self[next]) {
+                        sets.union(
+                            point_as_arr_idx(self.dimensions, point),
+                            point_as_arr_idx(self.dimensions, next),
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut label_of_root = vec![None; self.grid.len()];
+        let mut regions = Vec::new();
+        let labels = Grid {
+            dimensions: self.dimensions,
+            grid: (0..self.grid.len())
+                .map(|idx| {
+                    let root = sets.find_root(idx).unwrap();
+                    *label_of_root[root].get_or_insert_with(|| {
+                        regions.push(Region {
+                            area: 0,
+                            perimeter: 0,
+                            sides: 0,
+                        });
+                        regions.len() - 1
+                    })
+                })
+                .collect(),
+        };
+
+        let same_label = |point: grid_point::GridPoint<usize>, dir: direction::Direction| {
+            point
+                .add_checked(dir.into(), &labels.dimensions)
+                .filter(|next| labels[*next] == labels[point])
+        };
+        for point in labels.iter_points() {
+            let region = &mut regions[labels[point]];
+            region.area += 1;
+            for dir in direction::Direction::all() {
+                if same_label(point, dir).is_some() {
+                    continue;
+                }
+                region.perimeter += 1;
+                // a fence only starts a new side if the cell before it along
+                // the fence doesn't have a matching fence of its own
+                if same_label(point, dir.rotate_left())
+                    .is_none_or(|prev| same_label(prev, dir).is_some())
+                {
+                    region.sides += 1;
+                }
+            }
+        }
+        (labels, regions)
+    }
+}
+
+////////////
+/// Grid transformations
+///
+/// rotations and reflections, both in place and as copies
+////////////
+
+impl<T> Grid<T> {
+    // rebuild the grid with the given size, where each new point takes its value
+    // from the old point returned by source
+    fn permute<F: Fn(grid_point::GridPoint<usize>) -> grid_point::GridPoint<usize>>(
+        &mut self,
+        rows: usize,
+        cols: usize,
+        source: F,
+    ) {
+        let old_dimensions = self.dimensions;
+        let mut old: Vec<Option<T>> = std::mem::take(&mut self.grid)
+            .into_iter()
+            .map(Some)
+            .collect();
+        self.dimensions = grid_dimension::GridDimensions::new(0, rows, 0, cols);
+        self.grid = (0..rows * cols)
+            .map(|idx| {
+                let point = source(point_of_arr_idx(self.dimensions, idx));
+                old[point_as_arr_idx(old_dimensions, point)].take().unwrap()
+            })
+            .collect();
+    }
+
+    pub fn rotate_cw(&mut self) {
+        let (rows, cols) = (self.rows(), self.cols());
+        self.permute(cols, rows, |p| {
+            grid_point::GridPoint::new(rows - 1 - p.col, p.row)
+        });
+    }
+
+    pub fn rotate_ccw(&mut self) {
+        let (rows, cols) = (self.rows(), self.cols());
+        self.permute(cols, rows, |p| {
+            grid_point::GridPoint::new(p.col, cols - 1 - p.row)
+        });
+    }
+
+    pub fn transpose(&mut self) {
+        let (rows, cols) = (self.rows(), self.cols());
+        self.permute(cols, rows, |p| grid_point::GridPoint::new(p.col, p.row));
+    }
+
+    // mirror left to right, reversing each row
+    pub fn flip_horizontal(&mut self) {
+        let (rows, cols) = (self.rows(), self.cols());
+        self.permute(rows, cols, |p| {
+            grid_point::GridPoint::new(p.row, cols - 1 - p.col)
+        });
+    }
+
+    // mirror top to bottom, reversing the order of the rows
+    pub fn flip_vertical(&mut self) {
+        let (rows, cols) = (self.rows(), self.cols());
+        self.permute(rows, cols, |p| {
+            grid_point::GridPoint::new(rows - 1 - p.row, p.col)
+        });
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn rotated_cw(&self) -> Self {
+        let mut grid = self.clone();
+        grid.rotate_cw();
+        grid
+    }
+
+    pub fn rotated_ccw(&self) -> Self {
+        let mut grid = self.clone();
+        grid.rotate_ccw();
+        grid
+    }
+
+    pub fn transposed(&self) -> Self {
+        let mut grid = self.clone();
+        grid.transpose();
+        grid
+    }
+
+    pub fn flipped_horizontal(&self) -> Self {
+        let mut grid = self.clone();
+        grid.flip_horizontal();
+        grid
+    }
+
+    pub fn flipped_vertical(&self) -> Self {
+        let mut grid = self.clone();
+        grid.flip_vertical();
+        grid
+    }
+
+    pub fn subgrid(&self, dimensions: grid_dimension::GridDimensions<usize>) -> Option<Self> {
+        if dimensions.min_row > dimensions.max_row
+            || dimensions.min_col > dimensions.max_col
+            || dimensions.max_row > self.rows()
+            || dimensions.max_col > self.cols()
+        {
+            return None;
+        }
+        Self::of_list_of_lists(
+            (dimensions.min_row..dimensions.max_row).map(|row| {
+                (dimensions.min_col..dimensions.max_col)
+                    .map(move |col| self[grid_point::GridPoint::new(row, col)].clone())
+            }),
+            dimensions.rows(),
+            dimensions.cols(),
+        )
+    }
+
+    // all 8 rotations and reflections, starting with the grid itself
+    // followed by its clockwise rotations, then the same for its mirror image
+    pub fn symmetries(&self) -> impl Iterator<Item = Self> {
+        let mut all = Vec::with_capacity(8);
+        for mut grid in [self.clone(), self.flipped_horizontal()] {
+            for _ in 0..4 {
+                let next = grid.rotated_cw();
+                all.push(grid);
+                grid = next;
+            }
+        }
+        all.into_iter()
+    }
+}
+
+////////////
+/// Grid resizing
+///
+/// adding and removing rows and columns, and building larger grids from smaller ones
+////////////
+
+impl<T> Grid<T> {
+    pub fn insert_row<I: IntoIterator<Item = T>>(&mut self, idx: usize, row: I) -> Option<()> {
+        let row = row.into_iter().collect::<Vec<T>>();
+        if idx > self.rows() || row.len() != self.cols() {
+            return None;
+        }
+        let start = idx * self.cols();
+        self.grid.splice(start..start, row);
+        self.dimensions.max_row += 1;
+        Some(())
+    }
+
+    pub fn insert_col<I: IntoIterator<Item = T>>(&mut self, idx: usize, col: I) -> Option<()> {
+        let col = col.into_iter().collect::<Vec<T>>();
+        if idx > self.cols() || col.len() != self.rows() {
+            return None;
+        }
+        let cols = self.cols();
+        let mut old = std::mem::take(&mut self.grid).into_iter();
+        self.grid = Vec::with_capacity(old.len() + col.len());
+        for value in col {
+            self.grid.extend(old.by_ref().take(idx));
+            self.grid.push(value);
+            self.grid.extend(old.by_ref().take(cols - idx));
+        }
+        self.dimensions.max_col += 1;
+        Some(())
+    }
+
+    pub fn remove_row(&mut self, idx: usize) -> Option<Vec<T>> {
+        if idx >= self.rows() {
+            return None;
+        }
+        let start = idx * self.cols();
+        let row = self.grid.drain(start..start + self.cols()).collect();
+        self.dimensions.max_row -= 1;
+        Some(row)
+    }
+
+    pub fn remove_col(&mut self, idx: usize) -> Option<Vec<T>> {
+        if idx >= self.cols() {
+            return None;
+        }
+        let cols = self.cols();
+        let mut col = Vec::with_capacity(self.rows());
+        let old = std::mem::take(&mut self.grid);
+        self.grid = Vec::with_capacity(old.len() - self.rows());
+        for (i, value) in old.into_iter().enumerate() {
+            if i % cols == idx {
+                col.push(value);
+            } else {
+                self.grid.push(value);
+            }
+        }
+        self.dimensions.max_col -= 1;
+        Some(col)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn pad(&self, border: usize, value: T) -> Self {
+        let cols = self.cols() + 2 * border;
+        // chunks can't split a buffer into empty rows
+        if self.cols() == 0 {
+            return Self::init(value, self.rows() + 2 * border, cols);
+        }
+        let mut grid = Vec::with_capacity((self.rows() + 2 * border) * cols);
+        grid.resize(border * cols, value.clone());
+        for row in self.grid.chunks(self.cols()) {
+            grid.resize(grid.len() + border, value.clone());
+            grid.extend_from_slice(row);
+            grid.resize(grid.len() + border, value.clone());
+        }
+        grid.resize(grid.len() + border * cols, value);
+        Grid {
+            dimensions: grid_dimension::GridDimensions::new(0, self.rows() + 2 * border, 0, cols),
+            grid,
+        }
+    }
+
+    // grids placed side by side, all must have the same number of rows
+    pub fn hstack<I: IntoIterator<Item = Self>>(grids: I) -> Option<Self> {
+        let grids = grids.into_iter().collect::<Vec<Self>>();
+        let rows = grids.first()?.rows();
+        if grids.iter().any(|grid| grid.rows() != rows) {
+            return None;
+        }
+        let cols = grids.iter().map(|grid| grid.cols()).sum();
+        Self::of_list_of_lists(
+            (0..rows).map(|row| {
+                grids.iter().flat_map(move |grid| {
+                    grid.grid[row * grid.cols()..(row + 1) * grid.cols()]
+                        .iter()
+                        .cloned()
+                })
+            }),
+            rows,
+            cols,
+        )
+    }
+
+    // grids placed one above the other, all must have the same number of columns
+    pub fn vstack<I: IntoIterator<Item = Self>>(grids: I) -> Option<Self> {
+        let grids = grids.into_iter().collect::<Vec<Self>>();
+        let cols = grids.first()?.cols();
+        if grids.iter().any(|grid| grid.cols() != cols) {
+            return None;
+        }
+        let rows = grids.iter().map(|grid| grid.rows()).sum();
+        Self::from(grids.into_iter().flat_map(|grid| grid.grid), rows, cols)
+    }
+
+    pub fn tile(&self, tile_rows: usize, tile_cols: usize) -> Self {
+        self.tile_with(tile_rows, tile_cols, |value, _, _| value.clone())
+    }
+
+    // repeat the grid tile_rows times down and tile_cols times across, f gets
+    // each value along with the row and column of the tile it's being placed in
+    pub fn tile_with<F: Fn(&T, usize, usize) -> T>(
+        &self,
+        tile_rows: usize,
+        tile_cols: usize,
+        f: F,
+    ) -> Self {
+        let dimensions = grid_dimension::GridDimensions::new(
+            0,
+            self.rows() * tile_rows,
+            0,
+            self.cols() * tile_cols,
+        );
+        Grid {
+            dimensions,
+            grid: (0..dimensions.area())
+                .map(|idx| {
+                    let point = point_of_arr_idx(dimensions, idx);
+                    f(
+                        &self[grid_point::GridPoint::new(
+                            point.row % self.rows(),
+                            point.col % self.cols(),
+                        )],
+                        point.row / self.rows(),
+                        point.col / self.cols(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
 impl<T> Index<grid_point::GridPoint<usize>> for Grid<T> {
     type Output = T;
 
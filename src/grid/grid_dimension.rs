@@ -10,7 +10,7 @@ use std::{
 /// datastructure for identifying Grid Dimentions
 /////////////
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct GridDimensions<T> {
     pub min_row: T,
     pub max_row: T,
@@ -91,6 +91,186 @@ where
     }
 }
 
+////////////
+/// Grid Dimension set algebra
+///
+/// treating dimensions as the set of points they contain
+////////////
+
+impl<T> GridDimensions<T>
+where
+    T: Step + Ord + Clone,
+{
+    pub fn is_empty(&self) -> bool {
+        self.min_row >= self.max_row || self.min_col >= self.max_col
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = GridDimensions {
+            min_row: self.min_row.clone().max(other.min_row.clone()),
+            max_row: self.max_row.clone().min(other.max_row.clone()),
+            min_col: self.min_col.clone().max(other.min_col.clone()),
+            max_col: self.max_col.clone().min(other.max_col.clone()),
+        };
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    // smallest dimensions containing both, empty dimensions are ignored
+    pub fn union_bounds(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            return other.clone();
+        }
+        if other.is_empty() {
+            return self.clone();
+        }
+        GridDimensions {
+            min_row: self.min_row.clone().min(other.min_row.clone()),
+            max_row: self.max_row.clone().max(other.max_row.clone()),
+            min_col: self.min_col.clone().min(other.min_col.clone()),
+            max_col: self.max_col.clone().max(other.max_col.clone()),
+        }
+    }
+
+    // the points of self not in other, as up to four non overlapping rectangles:
+    // full width bands above and below the overlap, then pieces either side of it
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(overlap) = self.intersection(other) else {
+            return if self.is_empty() {
+                vec![]
+            } else {
+                vec![self.clone()]
+            };
+        };
+        [
+            GridDimensions::new(
+                self.min_row.clone(),
+                overlap.min_row.clone(),
+                self.min_col.clone(),
+                self.max_col.clone(),
+            ),
+            GridDimensions::new(
+                overlap.max_row.clone(),
+                self.max_row.clone(),
+                self.min_col.clone(),
+                self.max_col.clone(),
+            ),
+            GridDimensions::new(
+                overlap.min_row.clone(),
+                overlap.max_row.clone(),
+                self.min_col.clone(),
+                overlap.min_col.clone(),
+            ),
+            GridDimensions::new(
+                overlap.min_row,
+                overlap.max_row,
+                overlap.max_col,
+                self.max_col.clone(),
+            ),
+        ]
+        .into_iter()
+        .filter(|piece| !piece.is_empty())
+        .collect()
+    }
+
+    // rows before row, and rows from row onwards, either may be empty
+    pub fn split_at_row(&self, row: T) -> (Self, Self) {
+        let row = row.clamp(self.min_row.clone(), self.max_row.clone());
+        (
+            GridDimensions::new(
+                self.min_row.clone(),
+                row.clone(),
+                self.min_col.clone(),
+                self.max_col.clone(),
+            ),
+            GridDimensions::new(
+                row,
+                self.max_row.clone(),
+                self.min_col.clone(),
+                self.max_col.clone(),
+            ),
+        )
+    }
+
+    // cols before col, and cols from col onwards, either may be empty
+    pub fn split_at_col(&self, col: T) -> (Self, Self) {
+        let col = col.clamp(self.min_col.clone(), self.max_col.clone());
+        (
+            GridDimensions::new(
+                self.min_row.clone(),
+                self.max_row.clone(),
+                self.min_col.clone(),
+                col.clone(),
+            ),
+            GridDimensions::new(
+                self.min_row.clone(),
+                self.max_row.clone(),
+                col,
+                self.max_col.clone(),
+            ),
+        )
+    }
+
+    // None if the grown dimensions don't fit in T
+    pub fn expand(&self, margin: usize) -> Option<Self> {
+        Some(GridDimensions {
+            min_row: Step::backward_checked(self.min_row.clone(), margin)?,
+            max_row: Step::forward_checked(self.max_row.clone(), margin)?,
+            min_col: Step::backward_checked(self.min_col.clone(), margin)?,
+            max_col: Step::forward_checked(self.max_col.clone(), margin)?,
+        })
+    }
+
+    // top left, top right, bottom right and bottom left
+    pub fn corners(&self) -> Option<[grid_point::GridPoint<T>; 4]> {
+        if self.is_empty() {
+            return None;
+        }
+        let last_row = Step::backward(self.max_row.clone(), 1);
+        let last_col = Step::backward(self.max_col.clone(), 1);
+        Some([
+            grid_point::GridPoint::new(self.min_row.clone(), self.min_col.clone()),
+            grid_point::GridPoint::new(self.min_row.clone(), last_col.clone()),
+            grid_point::GridPoint::new(last_row.clone(), last_col),
+            grid_point::GridPoint::new(last_row, self.min_col.clone()),
+        ])
+    }
+
+    // each point on the edge once, clockwise from the top left corner
+    pub fn perimeter_points(&self) -> impl Iterator<Item = grid_point::GridPoint<T>> {
+        let mut points = Vec::new();
+        if let Some([top_left, _, bottom_right, _]) = self.corners() {
+            let (first_row, last_row) = (top_left.row, bottom_right.row);
+            let (first_col, last_col) = (top_left.col, bottom_right.col);
+            for col in first_col.clone()..=last_col.clone() {
+                points.push(grid_point::GridPoint::new(first_row.clone(), col));
+            }
+            for row in Step::forward(first_row.clone(), 1)..=last_row.clone() {
+                points.push(grid_point::GridPoint::new(row, last_col.clone()));
+            }
+            if last_row > first_row {
+                for col in (first_col.clone()..last_col.clone()).rev() {
+                    points.push(grid_point::GridPoint::new(last_row.clone(), col));
+                }
+            }
+            if last_col > first_col {
+                for row in (Step::forward(first_row, 1)..last_row).rev() {
+                    points.push(grid_point::GridPoint::new(row, first_col.clone()));
+                }
+            }
+        }
+        points.into_iter()
+    }
+
+    // nearest contained point, None if the dimensions are empty
+    pub fn clamp(&self, point: grid_point::GridPoint<T>) -> Option<grid_point::GridPoint<T>> {
+        let [top_left, _, bottom_right, _] = self.corners()?;
+        Some(grid_point::GridPoint::new(
+            point.row.clamp(top_left.row, bottom_right.row),
+            point.col.clamp(top_left.col, bottom_right.col),
+        ))
+    }
+}
+
 impl<T: Display> Display for GridDimensions<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
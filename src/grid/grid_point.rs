@@ -3,7 +3,7 @@ use std::{
     ops::{Add, AddAssign, Mul, Neg, Rem, Sub, SubAssign},
 };
 
-use super::grid_dimension;
+use super::{direction, grid_dimension};
 /////////////
 /// Grid Point
 ///
@@ -179,6 +179,139 @@ impl<T> GridPoint<T> {
     }
 }
 
+////////////
+/// Safe arithmetic
+///
+/// alternatives to Add which never panic, the sums are done in i128 so
+/// they can't overflow before being brought back into range
+////////////
+
+fn sum_i128<T: TryInto<i128>, S: TryInto<i128>>(value: T, delta: S) -> Option<i128> {
+    value.try_into().ok()?.checked_add(delta.try_into().ok()?)
+}
+
+// the range [min, max) as i128s, None if it's empty
+fn range_i128<T: TryInto<i128>>(min: T, max: T) -> Option<(i128, i128)> {
+    let min = min.try_into().ok()?;
+    let max = max.try_into().ok()?;
+    if min < max {
+        Some((min, max))
+    } else {
+        None
+    }
+}
+
+impl<T> GridPoint<T>
+where
+    T: TryInto<i128> + TryFrom<i128> + Copy,
+{
+    // None if the result can't be represented in T
+    pub fn checked_add<S: TryInto<i128>>(self, rhs: GridPointDelta<S>) -> Option<Self> {
+        Some(GridPoint::new(
+            T::try_from(sum_i128(self.row, rhs.row_delta)?).ok()?,
+            T::try_from(sum_i128(self.col, rhs.col_delta)?).ok()?,
+        ))
+    }
+
+    // wraps around within the dimensions, None if they're empty or the
+    // delta doesn't fit in an i128
+    pub fn wrapping_add<S: TryInto<i128>>(
+        self,
+        rhs: GridPointDelta<S>,
+        grid_dimensions: &grid_dimension::GridDimensions<T>,
+    ) -> Option<Self> {
+        let wrap = |value: T, delta: S, min: T, max: T| {
+            let (min, max) = range_i128(min, max)?;
+            let offset = sum_i128(value, delta)?.checked_sub(min)?;
+            T::try_from(min + offset.rem_euclid(max.checked_sub(min)?)).ok()
+        };
+        Some(GridPoint::new(
+            wrap(
+                self.row,
+                rhs.row_delta,
+                grid_dimensions.min_row,
+                grid_dimensions.max_row,
+            )?,
+            wrap(
+                self.col,
+                rhs.col_delta,
+                grid_dimensions.min_col,
+                grid_dimensions.max_col,
+            )?,
+        ))
+    }
+
+    // stops at the edge of the dimensions rather than at the bounds of T
+    // like the integer saturating_add, None if they're empty or the delta
+    // doesn't fit in an i128
+    pub fn saturating_add<S: TryInto<i128>>(
+        self,
+        rhs: GridPointDelta<S>,
+        grid_dimensions: &grid_dimension::GridDimensions<T>,
+    ) -> Option<Self> {
+        let clamp = |value: T, delta: S, min: T, max: T| {
+            let (min, max) = range_i128(min, max)?;
+            T::try_from(sum_i128(value, delta)?.clamp(min, max - 1)).ok()
+        };
+        Some(GridPoint::new(
+            clamp(
+                self.row,
+                rhs.row_delta,
+                grid_dimensions.min_row,
+                grid_dimensions.max_row,
+            )?,
+            clamp(
+                self.col,
+                rhs.col_delta,
+                grid_dimensions.min_col,
+                grid_dimensions.max_col,
+            )?,
+        ))
+    }
+
+    pub fn try_step(self, direction: direction::Direction) -> Option<Self> {
+        self.checked_add(GridPointDelta::<isize>::from(direction))
+    }
+}
+
+impl GridPoint<usize> {
+    // None if either coordinate is above isize::MAX
+    pub fn signed(self) -> Option<GridPoint<isize>> {
+        self.try_as_type().ok()
+    }
+}
+
+impl GridPoint<isize> {
+    // None if either coordinate is negative
+    pub fn unsigned(self) -> Option<GridPoint<usize>> {
+        self.try_as_type().ok()
+    }
+}
+
+macro_rules! grid_point_metric_impl {
+    ($integer:ty) => {
+        impl GridPoint<$integer> {
+            pub fn manhattan_distance(&self, other: &Self) -> usize {
+                self.row.abs_diff(other.row) as usize + self.col.abs_diff(other.col) as usize
+            }
+
+            pub fn chebyshev_distance(&self, other: &Self) -> usize {
+                (self.row.abs_diff(other.row) as usize).max(self.col.abs_diff(other.col) as usize)
+            }
+
+            // None if the result doesn't fit in a usize
+            pub fn squared_euclidean_distance(&self, other: &Self) -> Option<usize> {
+                let row = self.row.abs_diff(other.row) as usize;
+                let col = self.col.abs_diff(other.col) as usize;
+                row.checked_mul(row)?.checked_add(col.checked_mul(col)?)
+            }
+        }
+    };
+}
+
+grid_point_metric_impl!(usize);
+grid_point_metric_impl!(isize);
+
 ////////////
 /// Grid Point Delta
 ///
@@ -317,6 +450,38 @@ macro_rules! grid_point_delta_impl {
                     col_delta: self.col_delta / div,
                 }
             }
+
+            pub fn linf_norm(self) -> $integer {
+                self.row_delta.abs().max(self.col_delta.abs())
+            }
+
+            // None if the result doesn't fit in the coordinate type
+            pub fn squared_norm(self) -> Option<$integer> {
+                self.row_delta
+                    .checked_mul(self.row_delta)?
+                    .checked_add(self.col_delta.checked_mul(self.col_delta)?)
+            }
+
+            // a quarter turn clockwise, so north becomes east
+            pub fn rotate_right(self) -> Self {
+                GridPointDelta {
+                    row_delta: self.col_delta,
+                    col_delta: -self.row_delta,
+                }
+            }
+
+            // a quarter turn anticlockwise, so north becomes west
+            pub fn rotate_left(self) -> Self {
+                GridPointDelta {
+                    row_delta: -self.col_delta,
+                    col_delta: self.row_delta,
+                }
+            }
+
+            // clockwise quarter turns, negative turns go anticlockwise
+            pub fn rotate_quarter_turns(self, turns: i32) -> Self {
+                (0..turns.rem_euclid(4)).fold(self, |delta, _| delta.rotate_right())
+            }
         }
     };
 }
@@ -0,0 +1,450 @@
+use std::{
+    collections::{hash_map, HashMap},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub},
+};
+
+////////////
+/// Hex Point
+///
+/// axial coordinates on a hex grid, the third cube coordinate s is implied
+/// by q + r + s = 0. both layouts share these coordinates, flat topped grids
+/// step with HexDirection and pointy topped ones with PointyHexDirection
+////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexPoint {
+    pub q: isize,
+    pub r: isize,
+}
+
+impl HexPoint {
+    pub fn new(q: isize, r: isize) -> Self {
+        HexPoint { q, r }
+    }
+
+    pub fn origin() -> Self {
+        HexPoint { q: 0, r: 0 }
+    }
+
+    pub fn of_cube(q: isize, r: isize, s: isize) -> Option<Self> {
+        (q + r + s == 0).then_some(HexPoint { q, r })
+    }
+
+    pub fn s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    pub fn cube(&self) -> (isize, isize, isize) {
+        (self.q, self.r, self.s())
+    }
+
+    pub fn distance(&self, other: &Self) -> usize {
+        let delta = *self - *other;
+        (delta.q.unsigned_abs() + delta.r.unsigned_abs() + delta.s().unsigned_abs()) / 2
+    }
+
+    pub fn neighbors(self) -> [Self; 6] {
+        HexDirection::all().map(|direction| self + direction)
+    }
+
+    // all points exactly radius steps away, clockwise starting from the north
+    pub fn ring(self, radius: usize) -> impl Iterator<Item = Self> {
+        let start = self + HexDirection::North * radius as isize;
+        let steps = if radius == 0 { 1 } else { 6 * radius };
+        (0..steps).scan(start, move |point, step| {
+            let current = *point;
+            if let Some(side) = step.checked_div(radius) {
+                *point += HexDirection::all()[(side + 2) % 6];
+            }
+            Some(current)
+        })
+    }
+
+    // all points at most radius steps away, ring by ring outwards from self
+    pub fn spiral(self, radius: usize) -> impl Iterator<Item = Self> {
+        (0..=radius).flat_map(move |ring| self.ring(ring))
+    }
+}
+
+impl Add<HexPoint> for HexPoint {
+    type Output = Self;
+
+    fn add(self, rhs: HexPoint) -> Self::Output {
+        HexPoint::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl Sub<HexPoint> for HexPoint {
+    type Output = Self;
+
+    fn sub(self, rhs: HexPoint) -> Self::Output {
+        HexPoint::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl Neg for HexPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        HexPoint::new(-self.q, -self.r)
+    }
+}
+
+impl Mul<isize> for HexPoint {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        HexPoint::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl Add<HexDirection> for HexPoint {
+    type Output = Self;
+
+    fn add(self, rhs: HexDirection) -> Self::Output {
+        self + HexPoint::from(rhs)
+    }
+}
+
+impl AddAssign<HexDirection> for HexPoint {
+    fn add_assign(&mut self, rhs: HexDirection) {
+        *self = *self + rhs;
+    }
+}
+
+impl Add<PointyHexDirection> for HexPoint {
+    type Output = Self;
+
+    fn add(self, rhs: PointyHexDirection) -> Self::Output {
+        self + HexPoint::from(rhs)
+    }
+}
+
+impl AddAssign<PointyHexDirection> for HexPoint {
+    fn add_assign(&mut self, rhs: PointyHexDirection) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<HexPoint> for HexPoint {
+    fn add_assign(&mut self, rhs: HexPoint) {
+        *self = *self + rhs;
+    }
+}
+
+////////////
+/// Hex Direction
+///
+/// the six neighbors of a flat topped hex, listed clockwise from north
+////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl HexDirection {
+    pub fn all() -> [Self; 6] {
+        [
+            Self::North,
+            Self::NorthEast,
+            Self::SouthEast,
+            Self::South,
+            Self::SouthWest,
+            Self::NorthWest,
+        ]
+    }
+
+    // the abbreviations used by puzzle input, n, ne, se, s, sw and nw
+    pub fn of_str(s: &str) -> Option<Self> {
+        match s {
+            "n" => Some(Self::North),
+            "ne" => Some(Self::NorthEast),
+            "se" => Some(Self::SouthEast),
+            "s" => Some(Self::South),
+            "sw" => Some(Self::SouthWest),
+            "nw" => Some(Self::NorthWest),
+            _ => None,
+        }
+    }
+
+    pub fn rotate_left(self) -> Self {
+        match self {
+            Self::North => Self::NorthWest,
+            Self::NorthEast => Self::North,
+            Self::SouthEast => Self::NorthEast,
+            Self::South => Self::SouthEast,
+            Self::SouthWest => Self::South,
+            Self::NorthWest => Self::SouthWest,
+        }
+    }
+
+    pub fn rotate_right(self) -> Self {
+        match self {
+            Self::North => Self::NorthEast,
+            Self::NorthEast => Self::SouthEast,
+            Self::SouthEast => Self::South,
+            Self::South => Self::SouthWest,
+            Self::SouthWest => Self::NorthWest,
+            Self::NorthWest => Self::North,
+        }
+    }
+}
+
+impl From<HexDirection> for HexPoint {
+    fn from(value: HexDirection) -> Self {
+        match value {
+            HexDirection::North => HexPoint::new(0, -1),
+            HexDirection::NorthEast => HexPoint::new(1, -1),
+            HexDirection::SouthEast => HexPoint::new(1, 0),
+            HexDirection::South => HexPoint::new(0, 1),
+            HexDirection::SouthWest => HexPoint::new(-1, 1),
+            HexDirection::NorthWest => HexPoint::new(-1, 0),
+        }
+    }
+}
+
+impl Neg for HexDirection {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.rotate_right().rotate_right().rotate_right()
+    }
+}
+
+impl Mul<isize> for HexDirection {
+    type Output = HexPoint;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        HexPoint::from(self) * rhs
+    }
+}
+
+////////////
+/// Pointy Hex Direction
+///
+/// the six neighbors of a pointy topped hex, listed clockwise from east
+////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointyHexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl PointyHexDirection {
+    pub fn all() -> [Self; 6] {
+        [
+            Self::East,
+            Self::SouthEast,
+            Self::SouthWest,
+            Self::West,
+            Self::NorthWest,
+            Self::NorthEast,
+        ]
+    }
+
+    // the abbreviations used by puzzle input, e, se, sw, w, nw and ne
+    pub fn of_str(s: &str) -> Option<Self> {
+        match s {
+            "e" => Some(Self::East),
+            "se" => Some(Self::SouthEast),
+            "sw" => Some(Self::SouthWest),
+            "w" => Some(Self::West),
+            "nw" => Some(Self::NorthWest),
+            "ne" => Some(Self::NorthEast),
+            _ => None,
+        }
+    }
+
+    pub fn rotate_left(self) -> Self {
+        match self {
+            Self::East => Self::NorthEast,
+            Self::SouthEast => Self::East,
+            Self::SouthWest => Self::SouthEast,
+            Self::West => Self::SouthWest,
+            Self::NorthWest => Self::West,
+            Self::NorthEast => Self::NorthWest,
+        }
+    }
+
+    pub fn rotate_right(self) -> Self {
+        match self {
+            Self::East => Self::SouthEast,
+            Self::SouthEast => Self::SouthWest,
+            Self::SouthWest => Self::West,
+            Self::West => Self::NorthWest,
+            Self::NorthWest => Self::NorthEast,
+            Self::NorthEast => Self::East,
+        }
+    }
+}
+
+impl From<PointyHexDirection> for HexPoint {
+    fn from(value: PointyHexDirection) -> Self {
+        match value {
+            PointyHexDirection::East => HexPoint::new(1, 0),
+            PointyHexDirection::SouthEast => HexPoint::new(0, 1),
+            PointyHexDirection::SouthWest => HexPoint::new(-1, 1),
+            PointyHexDirection::West => HexPoint::new(-1, 0),
+            PointyHexDirection::NorthWest => HexPoint::new(0, -1),
+            PointyHexDirection::NorthEast => HexPoint::new(1, -1),
+        }
+    }
+}
+
+impl Neg for PointyHexDirection {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.rotate_right().rotate_right().rotate_right()
+    }
+}
+
+impl Mul<isize> for PointyHexDirection {
+    type Output = HexPoint;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        HexPoint::from(self) * rhs
+    }
+}
+
+////////////
+/// Hex Lattice
+///
+/// sparse storage of values on a hex grid, like Lattice
+////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexLattice<T> {
+    points: HashMap<HexPoint, T>,
+}
+
+impl<T> HexLattice<T> {
+    pub fn empty() -> Self {
+        HexLattice {
+            points: HashMap::new(),
+        }
+    }
+
+    pub fn from<I: IntoIterator<Item = (HexPoint, T)>>(iter: I) -> Self {
+        HexLattice {
+            points: iter.into_iter().collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn contains(&self, point: HexPoint) -> bool {
+        self.points.contains_key(&point)
+    }
+
+    pub fn get(&self, point: HexPoint) -> Option<&T> {
+        self.points.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: HexPoint) -> Option<&mut T> {
+        self.points.get_mut(&point)
+    }
+
+    pub fn set(&mut self, point: HexPoint, value: T) -> Option<T> {
+        self.points.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: HexPoint) -> Option<T> {
+        self.points.remove(&point)
+    }
+
+    pub fn entry(&mut self, point: HexPoint) -> HexEntry<'_, T> {
+        HexEntry(self.points.entry(point))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (HexPoint, &T)> {
+        self.points.iter().map(|(point, value)| (*point, value))
+    }
+
+    // only neighbors which are present in the lattice are returned
+    pub fn neighbors(&self, point: HexPoint) -> impl Iterator<Item = (HexPoint, &T)> {
+        point
+            .neighbors()
+            .into_iter()
+            .filter_map(|neighbor| Some((neighbor, self.get(neighbor)?)))
+    }
+}
+
+impl<T> IntoIterator for HexLattice<T> {
+    type Item = (HexPoint, T);
+    type IntoIter = hash_map::IntoIter<HexPoint, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.points.into_iter()
+    }
+}
+
+impl<T> Index<HexPoint> for HexLattice<T> {
+    type Output = T;
+
+    fn index(&self, index: HexPoint) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<T> IndexMut<HexPoint> for HexLattice<T> {
+    fn index_mut(&mut self, index: HexPoint) -> &mut Self::Output {
+        self.get_mut(index).unwrap()
+    }
+}
+
+////////////
+/// HexEntry
+///
+/// Used for modifying hex lattice entries
+////////////
+
+pub struct HexEntry<'a, T: 'a>(hash_map::Entry<'a, HexPoint, T>);
+
+impl<'a, T> HexEntry<'a, T> {
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        self.0.or_insert(default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> T>(self, default: F) -> &'a mut T {
+        self.0.or_insert_with(default)
+    }
+
+    pub fn or_insert_with_key<F: FnOnce(&HexPoint) -> T>(self, default: F) -> &'a mut T {
+        self.0.or_insert_with_key(default)
+    }
+
+    pub fn key(&self) -> &HexPoint {
+        self.0.key()
+    }
+
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T),
+    {
+        HexEntry(self.0.and_modify(f))
+    }
+}
+
+impl<'a, T: Default> HexEntry<'a, T> {
+    pub fn or_default(self) -> &'a mut T {
+        self.0.or_default()
+    }
+}
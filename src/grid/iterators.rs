@@ -70,6 +70,58 @@ impl grid_point::GridPoint<isize> {
     }
 }
 
+impl grid_point::GridPoint<isize> {
+    // the points at exactly radius manhattan distance, clockwise from the north
+    pub fn manhattan_circle(self, radius: usize) -> impl Iterator<Item = Self> {
+        let r = radius as isize;
+        (0..(4 * radius).max(1)).map(move |idx| {
+            let (side, k) = match idx.checked_div(radius) {
+                None => (0, 0),
+                Some(side) => (side, (idx % radius) as isize),
+            };
+            let (row_delta, col_delta) = match side {
+                0 => (k - r, k),
+                1 => (k, r - k),
+                2 => (r - k, -k),
+                _ => (-k, k - r),
+            };
+            grid_point::GridPoint::new(self.row + row_delta, self.col + col_delta)
+        })
+    }
+
+    // the points at most radius manhattan distance, circle by circle outwards
+    pub fn manhattan_disk(self, radius: usize) -> impl Iterator<Item = Self> {
+        (0..=radius).flat_map(move |r| self.manhattan_circle(r))
+    }
+
+    // bresenham's line, including both ends
+    pub fn line_to(self, other: Self) -> impl Iterator<Item = Self> {
+        let row_step = (other.row - self.row).signum();
+        let col_step = (other.col - self.col).signum();
+        let row_dist = -(other.row - self.row).abs();
+        let col_dist = (other.col - self.col).abs();
+        let mut error = col_dist + row_dist;
+        let mut next = Some(self);
+        std::iter::from_fn(move || {
+            let current = next?;
+            next = (current != other).then(|| {
+                let mut point = current;
+                let doubled_error = 2 * error;
+                if doubled_error >= row_dist {
+                    error += row_dist;
+                    point.col += col_step;
+                }
+                if doubled_error <= col_dist {
+                    error += col_dist;
+                    point.row += row_step;
+                }
+                point
+            });
+            Some(current)
+        })
+    }
+}
+
 ////////////
 /// GridLikeIterator
 ///
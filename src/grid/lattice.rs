@@ -1,4 +1,4 @@
-use super::{block, grid_dimension, grid_point, iterators};
+use super::{block, grid, grid_dimension, grid_point, iterators, neighborhood};
 use std::{
     collections::{hash_map, HashMap},
     iter::IntoIterator,
@@ -33,6 +33,35 @@ impl<T: Clone> Lattice<T> {
     }
 }
 
+impl<T: Clone> Lattice<T> {
+    // the grid covering the bounding box, missing points filled with default,
+    // along with the lattice point that the grid's (0, 0) corresponds to
+    pub fn to_grid(&self, default: T) -> Option<(grid::Grid<T>, grid_point::GridPoint<isize>)> {
+        let bounds = self.bounding_box()?;
+        let origin = grid_point::GridPoint::new(bounds.min_row, bounds.min_col);
+        let mut grid = grid::Grid::init(default, bounds.rows() as usize, bounds.cols() as usize);
+        for (point, value) in self.points.iter() {
+            grid[grid_point::GridPoint::new(
+                (point.row - origin.row) as usize,
+                (point.col - origin.col) as usize,
+            )] = value.clone();
+        }
+        Some((grid, origin))
+    }
+}
+
+impl<T> Lattice<T> {
+    pub fn of_grid(grid: grid::Grid<T>) -> Self {
+        Lattice {
+            points: grid
+                .into_iter()
+                // grid indices are bounded by the buffer length, which fits in an isize
+                .map(|(point, value)| (point.signed().unwrap(), value))
+                .collect(),
+        }
+    }
+}
+
 impl<T> IntoIterator for Lattice<T> {
     type IntoIter = iterators::IntoGridLikeIterator<
         isize,
@@ -63,10 +92,30 @@ impl<T> Lattice<T> {
         self.points.insert(point, value)
     }
 
+    pub fn remove(&mut self, point: grid_point::GridPoint<isize>) -> Option<T> {
+        self.points.remove(&point)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (grid_point::GridPoint<isize>, &T)> {
+        self.points.iter().map(|(point, value)| (*point, value))
+    }
+
     pub fn entry(&mut self, point: grid_point::GridPoint<isize>) -> LatticeEntry<'_, T> {
         LatticeEntry(self.points.entry(point))
     }
 
+    // only neighbors which are present in the lattice are returned
+    pub fn neighbors(
+        &self,
+        point: grid_point::GridPoint<isize>,
+        neighborhood: neighborhood::Neighborhood,
+    ) -> impl Iterator<Item = (grid_point::GridPoint<isize>, &T)> + '_ {
+        neighborhood.into_iter().filter_map(move |delta| {
+            let neighbor = point + delta;
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+
     pub fn bounding_box(&self) -> Option<grid_dimension::GridDimensions<isize>> {
         self.points
             .iter()
@@ -1,22 +1,40 @@
+mod automaton;
+mod bit_grid;
 mod block;
 mod constants;
 mod direction;
 mod error;
+mod geometry;
 mod grid;
 mod grid_dimension;
 mod grid_point;
+mod hex;
 mod iterators;
 mod lattice;
 mod maze;
+mod nd_grid;
+mod neighborhood;
+mod ocr;
+mod render;
+mod topology;
 
+pub use automaton::{CellularAutomaton, Edges, GridAutomaton, LatticeAutomaton};
+pub use bit_grid::{BitGrid, NeighborCounts};
 pub use block::Block;
 pub use constants::*;
-pub use direction::Direction;
-pub use grid::Grid;
+pub use direction::{Direction, Direction8, Turn};
+pub use geometry::Polygon;
+pub use grid::{Grid, Region};
 pub use grid_dimension::GridDimensions;
 pub use grid_point::{GridPoint, GridPointDelta};
+pub use hex::{HexDirection, HexLattice, HexPoint, PointyHexDirection};
 pub use lattice::Lattice;
 pub use maze::{Maze, MazeCell, MazeWithTurningCost};
+pub use nd_grid::{NdBounds, NdBoundsIterator, NdGrid, NdLattice, NdNeighborhood, NdPoint};
+pub use neighborhood::{Neighborhood, NeighborhoodIterator};
+pub use ocr::{ocr, ocr_lattice, OcrError};
+pub use render::Overlay;
+pub use topology::{Bounded, CubeNet, Topology, Toroidal, VoidWrap};
 
 ////////////
 /// Tests
@@ -25,6 +43,7 @@ pub use maze::{Maze, MazeCell, MazeWithTurningCost};
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     #[test]
     fn grid_init_test() {
@@ -60,4 +79,1224 @@ mod tests {
             ]
         );
     }
+
+    fn small_grid() -> Grid<u32> {
+        Grid::from(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap()
+    }
+
+    #[test]
+    fn grid_rotate_test() {
+        let mut grid = small_grid();
+        grid.rotate_cw();
+        assert_eq!(grid, Grid::from(vec![4, 1, 5, 2, 6, 3], 3, 2).unwrap());
+        grid.rotate_ccw();
+        assert_eq!(grid, small_grid());
+        assert_eq!(
+            small_grid().rotated_ccw(),
+            Grid::from(vec![3, 6, 2, 5, 1, 4], 3, 2).unwrap()
+        );
+        assert_eq!(
+            small_grid().rotated_cw().rotated_cw(),
+            Grid::from(vec![6, 5, 4, 3, 2, 1], 2, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn grid_flip_test() {
+        assert_eq!(
+            small_grid().flipped_horizontal(),
+            Grid::from(vec![3, 2, 1, 6, 5, 4], 2, 3).unwrap()
+        );
+        assert_eq!(
+            small_grid().flipped_vertical(),
+            Grid::from(vec![4, 5, 6, 1, 2, 3], 2, 3).unwrap()
+        );
+        let mut grid = small_grid();
+        grid.transpose();
+        assert_eq!(grid, Grid::from(vec![1, 4, 2, 5, 3, 6], 3, 2).unwrap());
+        assert_eq!(grid.transposed(), small_grid());
+    }
+
+    #[test]
+    fn grid_subgrid_test() {
+        assert_eq!(
+            small_grid().subgrid(GridDimensions::new(0, 2, 1, 3)),
+            Some(Grid::from(vec![2, 3, 5, 6], 2, 2).unwrap())
+        );
+        assert_eq!(
+            small_grid().subgrid(GridDimensions::new(1, 2, 0, 1)),
+            Some(Grid::from(vec![4], 1, 1).unwrap())
+        );
+        assert_eq!(small_grid().subgrid(GridDimensions::new(0, 3, 0, 1)), None);
+    }
+
+    #[test]
+    fn grid_symmetries_test() {
+        let symmetries = small_grid().symmetries().collect::<Vec<Grid<u32>>>();
+        assert_eq!(symmetries.len(), 8);
+        assert_eq!(symmetries[0], small_grid());
+        assert_eq!(symmetries[1], small_grid().rotated_cw());
+        assert_eq!(symmetries[4], small_grid().flipped_horizontal());
+        assert!(symmetries.contains(&small_grid().transposed()));
+        assert!(symmetries.contains(&small_grid().flipped_vertical()));
+        for (idx, a) in symmetries.iter().enumerate() {
+            assert!(!symmetries[idx + 1..].contains(a));
+        }
+    }
+
+    #[test]
+    fn grid_insert_remove_test() {
+        let mut grid = small_grid();
+        assert_eq!(grid.insert_row(1, vec![7, 8, 9]), Some(()));
+        assert_eq!(
+            grid,
+            Grid::from(vec![1, 2, 3, 7, 8, 9, 4, 5, 6], 3, 3).unwrap()
+        );
+        assert_eq!(grid.insert_row(1, vec![7, 8]), None);
+        assert_eq!(grid.insert_col(3, vec![0, 0, 0]), Some(()));
+        assert_eq!(
+            grid,
+            Grid::from(vec![1, 2, 3, 0, 7, 8, 9, 0, 4, 5, 6, 0], 3, 4).unwrap()
+        );
+        assert_eq!(grid.insert_col(5, vec![0, 0, 0]), None);
+        assert_eq!(grid.remove_col(0), Some(vec![1, 7, 4]));
+        assert_eq!(grid.remove_row(1), Some(vec![8, 9, 0]));
+        assert_eq!(grid.remove_row(2), None);
+        assert_eq!(grid, Grid::from(vec![2, 3, 0, 5, 6, 0], 2, 3).unwrap());
+    }
+
+    #[test]
+    fn grid_pad_test() {
+        assert_eq!(
+            Grid::from(vec![1, 2], 1, 2).unwrap().pad(1, 0),
+            Grid::from(vec![0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0], 3, 4).unwrap()
+        );
+        assert_eq!(small_grid().pad(0, 0), small_grid());
+        assert_eq!(
+            Grid::from(vec![], 2, 0).unwrap().pad(1, 0),
+            Grid::init(0, 4, 2)
+        );
+    }
+
+    #[test]
+    fn grid_stack_test() {
+        assert_eq!(
+            Grid::hstack([small_grid(), Grid::from(vec![7, 8], 2, 1).unwrap()]),
+            Some(Grid::from(vec![1, 2, 3, 7, 4, 5, 6, 8], 2, 4).unwrap())
+        );
+        assert_eq!(
+            Grid::vstack([small_grid(), Grid::from(vec![7, 8, 9], 1, 3).unwrap()]),
+            Some(Grid::from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3).unwrap())
+        );
+        assert_eq!(
+            Grid::hstack([small_grid(), small_grid().transposed()]),
+            None
+        );
+        assert_eq!(Grid::<u32>::vstack([]), None);
+    }
+
+    #[test]
+    fn grid_tile_test() {
+        let grid = Grid::from(vec![1, 2], 1, 2).unwrap();
+        assert_eq!(
+            grid.tile(2, 2),
+            Grid::from(vec![1, 2, 1, 2, 1, 2, 1, 2], 2, 4).unwrap()
+        );
+        assert_eq!(
+            grid.tile_with(2, 3, |v, row, col| v + 10 * (row + col) as u32),
+            Grid::from(vec![1, 2, 11, 12, 21, 22, 11, 12, 21, 22, 31, 32], 2, 6).unwrap()
+        );
+    }
+
+    #[test]
+    fn grid_neighbors_test() {
+        let grid = small_grid();
+        assert_eq!(
+            grid.neighbors(GridPoint::new(0, 0), Neighborhood::Plus)
+                .collect::<Vec<_>>(),
+            vec![(GridPoint::new(0, 1), &2), (GridPoint::new(1, 0), &4)]
+        );
+        assert_eq!(
+            grid.neighbors(GridPoint::new(1, 1), Neighborhood::Moore)
+                .map(|(_, v)| *v)
+                .collect::<Vec<_>>(),
+            vec![2, 3, 6, 4, 1]
+        );
+        assert_eq!(
+            grid.neighbors(GridPoint::new(0, 1), Neighborhood::Diagonal)
+                .map(|(_, v)| *v)
+                .collect::<Vec<_>>(),
+            vec![6, 4]
+        );
+        assert_eq!(
+            grid.neighbors(
+                GridPoint::new(0, 0),
+                Neighborhood::Custom(vec![GridPointDelta::new(1, 2), GridPointDelta::new(2, 1)])
+            )
+            .map(|(_, v)| *v)
+            .collect::<Vec<_>>(),
+            vec![6]
+        );
+    }
+
+    #[test]
+    fn grid_neighbors_wrapping_test() {
+        let grid = small_grid();
+        assert_eq!(
+            grid.neighbors_wrapping(GridPoint::new(0, 0), Neighborhood::Plus)
+                .collect::<Vec<_>>(),
+            vec![
+                (GridPoint::new(1, 0), &4),
+                (GridPoint::new(0, 1), &2),
+                (GridPoint::new(1, 0), &4),
+                (GridPoint::new(0, 2), &3)
+            ]
+        );
+        assert_eq!(
+            grid.neighbors_tiled(GridPoint::new(-1, 3), Neighborhood::Plus)
+                .collect::<Vec<_>>(),
+            vec![
+                (GridPoint::new(-2, 3), &1),
+                (GridPoint::new(-1, 4), &5),
+                (GridPoint::new(0, 3), &1),
+                (GridPoint::new(-1, 2), &6)
+            ]
+        );
+    }
+
+    #[test]
+    fn lattice_neighbors_test() {
+        let lattice = Lattice::from([
+            (GridPoint::new(0, 0), 'a'),
+            (GridPoint::new(-1, 0), 'b'),
+            (GridPoint::new(1, 1), 'c'),
+        ]);
+        assert_eq!(
+            lattice
+                .neighbors(GridPoint::new(0, 0), Neighborhood::Moore)
+                .collect::<Vec<_>>(),
+            vec![(GridPoint::new(-1, 0), &'b'), (GridPoint::new(1, 1), &'c')]
+        );
+        assert_eq!(
+            lattice
+                .neighbors(GridPoint::new(0, 0), Neighborhood::Diagonal)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn grid_flood_fill_test() {
+        let grid = Grid::of_vec_of_vecs(
+            ["#..#", "#.##", "..#.", "##.."]
+                .iter()
+                .map(|row| row.chars().collect())
+                .collect(),
+        )
+        .unwrap();
+        let region = grid.flood_fill(GridPoint::new(0, 1), |c| *c == '.');
+        assert_eq!(
+            region,
+            Block::from([
+                GridPoint::new(0, 1),
+                GridPoint::new(0, 2),
+                GridPoint::new(1, 1),
+                GridPoint::new(2, 1),
+                GridPoint::new(2, 0),
+            ])
+        );
+        assert_eq!(
+            grid.flood_fill(GridPoint::new(0, 0), |c| *c == '.'),
+            Block::empty()
+        );
+        assert_eq!(
+            grid.flood_fill(GridPoint::new(5, 0), |c| *c == '.'),
+            Block::empty()
+        );
+    }
+
+    #[test]
+    fn grid_connected_components_test() {
+        let grid = Grid::of_vec_of_vecs(
+            ["AAAA", "BBCD", "BBCC", "EEEC"]
+                .iter()
+                .map(|row| row.chars().collect())
+                .collect(),
+        )
+        .unwrap();
+        let (labels, regions) = grid.connected_components(|a, b| a == b);
+        assert_eq!(
+            labels,
+            Grid::from(vec![0, 0, 0, 0, 1, 1, 2, 3, 1, 1, 2, 2, 4, 4, 4, 2], 4, 4).unwrap()
+        );
+        assert_eq!(
+            regions
+                .iter()
+                .map(|region| (region.area, region.perimeter, region.sides))
+                .collect::<Vec<_>>(),
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+    }
+
+    fn life(alive: &bool, neighbors: &[&bool]) -> bool {
+        let count = neighbors.iter().filter(|n| ***n).count();
+        count == 3 || (*alive && count == 2)
+    }
+
+    #[test]
+    fn grid_automaton_test() {
+        let blinker = Grid::from(
+            [".....", "..#..", "..#..", "..#..", "....."]
+                .concat()
+                .chars()
+                .map(|c| c == '#'),
+            5,
+            5,
+        )
+        .unwrap();
+        let mut automaton =
+            GridAutomaton::new(blinker.clone(), Neighborhood::Moore, Edges::Bounded, life);
+        automaton.step();
+        assert_eq!(automaton.generation(), 1);
+        assert_eq!(automaton.state(), &blinker.transposed());
+        assert_eq!(automaton.jump_to(1_000_000_001), Some(2));
+        assert_eq!(automaton.generation(), 1_000_000_001);
+        assert_eq!(automaton.into_state(), blinker.transposed());
+
+        // a glider on a torus comes back to where it started
+        let glider = Grid::from(
+            [".#...", "..#..", "###..", ".....", "....."]
+                .concat()
+                .chars()
+                .map(|c| c == '#'),
+            5,
+            5,
+        )
+        .unwrap();
+        let mut automaton =
+            GridAutomaton::new(glider.clone(), Neighborhood::Moore, Edges::Wrapping, life);
+        automaton.run(20);
+        assert_eq!(automaton.state(), &glider);
+    }
+
+    #[test]
+    fn lattice_automaton_test() {
+        let glider = [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)]
+            .map(|(row, col)| (GridPoint::new(row, col), true));
+        let mut automaton =
+            LatticeAutomaton::new(Lattice::from(glider), false, Neighborhood::Moore, life);
+        automaton.run(4);
+        assert_eq!(
+            automaton.state(),
+            &Lattice::from(glider.map(|(point, alive)| (point + GridPointDelta::new(1, 1), alive)))
+        );
+        assert!(*automaton.get(GridPoint::new(3, 3)));
+        assert!(!*automaton.get(GridPoint::new(-100, 3)));
+        assert_eq!(automaton.jump_to(10), None);
+        assert_eq!(automaton.state().iter().count(), 5);
+    }
+
+    #[test]
+    fn grid_render_test() {
+        let grid = Grid::from(
+            ["#..", ".#.", "..#"].concat().chars().map(|c| c == '#'),
+            3,
+            3,
+        )
+        .unwrap();
+        let f = |cell: &bool| if *cell { '#' } else { '.' };
+        assert_eq!(grid.render(f), "#..\n.#.\n..#");
+        let overlay = Overlay::new()
+            .path([
+                GridPoint::new(0, 1),
+                GridPoint::new(0, 2),
+                GridPoint::new(1, 2),
+            ])
+            .highlight([GridPoint::new(2, 0)], 'O');
+        assert_eq!(grid.render_with_overlay(f, &overlay), "#>v\n.#X\nO.#");
+    }
+
+    #[test]
+    fn lattice_render_test() {
+        let lattice = Lattice::from([(GridPoint::new(-1, -1), 'a'), (GridPoint::new(0, 1), 'b')]);
+        let f = |cell: Option<&char>| *cell.unwrap_or(&' ');
+        assert_eq!(lattice.render(f), "a  \n  b");
+        assert_eq!(
+            lattice.render_with_overlay(f, &Overlay::new().highlight([GridPoint::new(0, -1)], '*')),
+            "a  \n* b"
+        );
+        assert_eq!(Lattice::<char>::empty().render(f), "");
+    }
+
+    #[test]
+    fn grid_image_test() {
+        let grid = Grid::from(vec![0u8, 255], 1, 2).unwrap();
+        let palette = |cell: &u8| [*cell, 0, 0];
+        let mut ppm = b"P6\n4 2\n255\n".to_vec();
+        for _ in 0..2 {
+            ppm.extend([0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0]);
+        }
+        assert_eq!(grid.to_ppm(palette, 2), ppm);
+
+        let png = grid.to_png(palette, 1);
+        assert_eq!(png[..8], [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a]);
+        // IHDR length, type, width and height
+        assert_eq!(
+            png[8..24],
+            [0, 0, 0, 13, b'I', b'H', b'D', b'R', 0, 0, 0, 2, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            png[png.len() - 12..],
+            [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]
+        );
+    }
+
+    fn bool_grid(rows: &[&str]) -> Grid<bool> {
+        Grid::of_vec_of_vecs(
+            rows.iter()
+                .map(|row| row.chars().map(|c| c == '#').collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn ocr_small_font_test() {
+        let grid = bool_grid(&[
+            "..........................",
+            ".#..#.###..####.#.....##..",
+            ".#..#..#...#....#....#..#.",
+            ".####..#...###..#....#..#.",
+            ".#..#..#...#....#....#..#.",
+            ".#..#..#...#....#....#..#.",
+            ".#..#.###..####.####..##..",
+        ]);
+        assert_eq!(ocr(&grid), Ok(String::from("HIELO")));
+        let lattice = Lattice::from(
+            grid.iter_points()
+                .filter(|p| grid[*p])
+                .map(|p| (GridPoint::new(p.row as isize - 10, p.col as isize + 3), ())),
+        );
+        assert_eq!(ocr_lattice(&lattice), Ok(String::from("HIELO")));
+    }
+
+    #[test]
+    fn ocr_large_font_test() {
+        let grid = bool_grid(&[
+            "#####...######",
+            "#....#.......#",
+            "#....#.......#",
+            "#....#......#.",
+            "#####......#..",
+            "#..#......#...",
+            "#...#....#....",
+            "#...#...#.....",
+            "#....#..#.....",
+            "#....#..######",
+        ]);
+        assert_eq!(ocr(&grid), Ok(String::from("RZ")));
+    }
+
+    #[test]
+    fn ocr_fixed_width_cells_test() {
+        // 2016 day 8 style display, Y fills its cell so it touches the Z
+        let grid = bool_grid(&[
+            "#....#...#####.#..#...............................",
+            "#....#...#...#.#..#...............................",
+            "#.....#.#...#..####...............................",
+            "#......#...#...#..#...............................",
+            "#......#..#....#..#...............................",
+            "####...#..####.#..#...............................",
+        ]);
+        assert_eq!(ocr(&grid), Ok(String::from("LYZH")));
+        // I sits one column into its cell, so cells can't be counted from it
+        let grid = bool_grid(&[
+            ".###.#...#####.#..#...........",
+            "..#..#...#...#.#..#...........",
+            "..#...#.#...#..####...........",
+            "..#....#...#...#..#...........",
+            "..#....#..#....#..#...........",
+            ".###...#..####.#..#...........",
+        ]);
+        assert_eq!(ocr(&grid), Ok(String::from("IYZH")));
+    }
+
+    #[test]
+    fn ocr_error_test() {
+        assert_eq!(ocr(&Grid::init(false, 6, 5)), Err(OcrError::Empty));
+        assert_eq!(
+            ocr(&bool_grid(&["#", "#", "#"])),
+            Err(OcrError::UnsupportedHeight(3))
+        );
+        let grid = bool_grid(&[
+            "#..#..#..#",
+            "#..#..##.#",
+            "####..#.##",
+            "#..#..#..#",
+            "#..#..#..#",
+            "#..#..#..#",
+        ]);
+        assert_eq!(
+            ocr(&grid),
+            Err(OcrError::UnrecognizedGlyphs(vec![GridDimensions::new(
+                0, 6, 6, 10
+            )]))
+        );
+    }
+
+    #[test]
+    fn bit_grid_get_set_test() {
+        let mut bit_grid = BitGrid::new(3, 130);
+        assert_eq!(bit_grid.set(GridPoint::new(1, 64), true), Ok(()));
+        assert_eq!(bit_grid.set(GridPoint::new(2, 129), true), Ok(()));
+        assert_eq!(bit_grid.set(GridPoint::new(0, 0), true), Ok(()));
+        assert_eq!(bit_grid.set(GridPoint::new(0, 0), false), Ok(()));
+        assert!(bit_grid.set(GridPoint::new(0, 130), true).is_err());
+        assert_eq!(bit_grid.get(GridPoint::new(1, 64)), Ok(true));
+        assert_eq!(bit_grid.get(GridPoint::new(1, 63)), Ok(false));
+        assert!(bit_grid.get(GridPoint::new(3, 0)).is_err());
+        assert_eq!(bit_grid.count_ones(), 2);
+        assert_eq!(bit_grid.row_count_ones(2), 1);
+        assert_eq!(
+            bit_grid.iter_ones().collect::<Vec<_>>(),
+            vec![GridPoint::new(1, 64), GridPoint::new(2, 129)]
+        );
+        assert_eq!(bit_grid.iter_points().count(), 390);
+        assert_eq!(BitGrid::from(&bit_grid.to_grid()), bit_grid);
+    }
+
+    #[test]
+    fn bit_grid_ops_test() {
+        let mut a = BitGrid::new(2, 100);
+        let mut b = BitGrid::new(2, 100);
+        a.set(GridPoint::new(0, 1), true).unwrap();
+        a.set(GridPoint::new(1, 70), true).unwrap();
+        b.set(GridPoint::new(1, 70), true).unwrap();
+        b.set(GridPoint::new(1, 99), true).unwrap();
+        assert_eq!(
+            (&a & &b).iter_ones().collect::<Vec<_>>(),
+            vec![GridPoint::new(1, 70)]
+        );
+        assert_eq!((&a | &b).count_ones(), 3);
+        assert_eq!((&a ^ &b).count_ones(), 2);
+        assert_eq!((!&a).count_ones(), 198);
+
+        let mut shifted = a.shifted(GridPointDelta::new(1, 65));
+        assert_eq!(
+            shifted.iter_ones().collect::<Vec<_>>(),
+            vec![GridPoint::new(1, 66)]
+        );
+        shifted.shift(GridPointDelta::new(-1, -66));
+        assert_eq!(
+            shifted.iter_ones().collect::<Vec<_>>(),
+            vec![GridPoint::new(0, 0)]
+        );
+        shifted.shift(WEST);
+        assert_eq!(shifted.count_ones(), 0);
+        assert_eq!(
+            b.shifted(EAST * 29).iter_ones().collect::<Vec<_>>(),
+            vec![GridPoint::new(1, 99)]
+        );
+    }
+
+    #[test]
+    fn bit_grid_neighbor_counts_test() {
+        let grid = bool_grid(&["#.##.", "##..#", "..#.#", "#####"]);
+        let counts = BitGrid::from(&grid).neighbor_counts(Neighborhood::Moore);
+        for point in grid.iter_points() {
+            assert_eq!(
+                counts.get(point),
+                Ok(grid
+                    .neighbors(point, Neighborhood::Moore)
+                    .filter(|(_, alive)| **alive)
+                    .count())
+            );
+        }
+        let threes = counts.equal_to(3);
+        assert_eq!(
+            threes.iter_ones().collect::<Vec<_>>(),
+            grid.iter_points()
+                .filter(|point| counts.get(*point) == Ok(3))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(counts.equal_to(9).count_ones(), 0);
+    }
+
+    #[test]
+    fn nd_bounds_test() {
+        let bounds =
+            NdBounds::of_points_inclusive(NdPoint::of_raw([1, -1, 0]), NdPoint::of_raw([0, 0, 2]));
+        assert_eq!(bounds.volume(), 12);
+        assert_eq!(bounds.iter().count(), 12);
+        assert_eq!(
+            bounds.iter().take(4).collect::<Vec<_>>(),
+            vec![
+                NdPoint::of_raw([0, -1, 0]),
+                NdPoint::of_raw([0, -1, 1]),
+                NdPoint::of_raw([0, -1, 2]),
+                NdPoint::of_raw([0, 0, 0]),
+            ]
+        );
+        assert!(bounds.contains(&NdPoint::of_raw([1, 0, 2])));
+        assert!(!bounds.contains(&NdPoint::of_raw([2, 0, 2])));
+        assert_eq!(
+            bounds.grow_to_contain(NdPoint::of_raw([3, 0, 0])).volume(),
+            24
+        );
+        assert_eq!(bounds.expand(1).volume(), 4 * 4 * 5);
+        assert_eq!(
+            NdBounds::new(NdPoint::of_raw([0, 0]), NdPoint::of_raw([0, 3]))
+                .iter()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn nd_neighborhood_test() {
+        assert_eq!(NdNeighborhood::<3>::Moore.deltas().len(), 26);
+        assert_eq!(NdNeighborhood::<4>::Moore.deltas().len(), 80);
+        assert_eq!(
+            NdNeighborhood::<2>::VonNeumann.deltas(),
+            vec![
+                NdPoint::of_raw([-1, 0]),
+                NdPoint::of_raw([1, 0]),
+                NdPoint::of_raw([0, -1]),
+                NdPoint::of_raw([0, 1]),
+            ]
+        );
+    }
+
+    #[test]
+    fn nd_grid_test() {
+        let mut lattice = NdLattice::from([
+            (NdPoint::of_raw([0, 0, 0]), 1),
+            (NdPoint::of_raw([1, 1, -1]), 2),
+        ]);
+        assert_eq!(lattice.len(), 2);
+        assert_eq!(
+            lattice.bounding_box(),
+            Some(NdBounds::new(
+                NdPoint::of_raw([0, 0, -1]),
+                NdPoint::of_raw([2, 2, 1])
+            ))
+        );
+        assert_eq!(
+            lattice
+                .neighbors(NdPoint::of_raw([0, 0, 0]), &NdNeighborhood::Moore)
+                .collect::<Vec<_>>(),
+            vec![(NdPoint::of_raw([1, 1, -1]), &2)]
+        );
+        assert_eq!(lattice.set(NdPoint::of_raw([1, 0, 0]), 3), None);
+
+        let mut grid = NdGrid::of_lattice(&lattice, 0).unwrap();
+        assert_eq!(grid.iter().filter(|(_, v)| **v > 0).count(), 3);
+        assert_eq!(grid[NdPoint::of_raw([1, 1, -1])], 2);
+        assert_eq!(grid.get(NdPoint::of_raw([2, 0, 0])), None);
+        assert_eq!(grid.set(NdPoint::of_raw([0, 1, -1]), 4), Some(0));
+        assert_eq!(
+            grid.neighbors(NdPoint::of_raw([0, 0, 0]), &NdNeighborhood::VonNeumann)
+                .map(|(_, v)| *v)
+                .collect::<Vec<_>>(),
+            vec![3, 0, 0]
+        );
+        assert_eq!(
+            grid.neighbors(NdPoint::of_raw([0, 0, 0]), &NdNeighborhood::Moore)
+                .map(|(_, v)| *v)
+                .sum::<i32>(),
+            9
+        );
+        assert_eq!(NdGrid::of_lattice(&NdLattice::<2, i32>::empty(), 0), None);
+    }
+
+    #[test]
+    fn hex_direction_test() {
+        for direction in HexDirection::all() {
+            assert_eq!(direction.rotate_left().rotate_right(), direction);
+            assert_eq!(
+                HexPoint::origin() + direction + -direction,
+                HexPoint::origin()
+            );
+        }
+        assert_eq!(HexDirection::North.rotate_left(), HexDirection::NorthWest);
+        assert_eq!(HexDirection::of_str("se"), Some(HexDirection::SouthEast));
+        assert_eq!(HexDirection::of_str("e"), None);
+    }
+
+    #[test]
+    fn pointy_hex_direction_test() {
+        for direction in PointyHexDirection::all() {
+            assert_eq!(direction.rotate_left().rotate_right(), direction);
+            assert_eq!(
+                HexPoint::origin() + direction + -direction,
+                HexPoint::origin()
+            );
+            assert_eq!(
+                (HexPoint::origin() + direction).distance(&HexPoint::origin()),
+                1
+            );
+        }
+        assert_eq!(
+            PointyHexDirection::East.rotate_left(),
+            PointyHexDirection::NorthEast
+        );
+        assert_eq!(-PointyHexDirection::East, PointyHexDirection::West);
+        assert_eq!(
+            PointyHexDirection::of_str("w"),
+            Some(PointyHexDirection::West)
+        );
+        assert_eq!(PointyHexDirection::of_str("n"), None);
+        let mut neighbors = PointyHexDirection::all().map(|d| HexPoint::origin() + d);
+        neighbors.sort_by_key(|point| (point.q, point.r));
+        let mut flat = HexPoint::origin().neighbors();
+        flat.sort_by_key(|point| (point.q, point.r));
+        assert_eq!(neighbors, flat);
+    }
+
+    #[test]
+    fn hex_distance_test() {
+        let walk = |path: &str| {
+            path.split(',')
+                .map(|step| HexDirection::of_str(step).unwrap())
+                .fold(HexPoint::origin(), |point, direction| point + direction)
+        };
+        assert_eq!(walk("ne,ne,ne").distance(&HexPoint::origin()), 3);
+        assert_eq!(walk("ne,ne,sw,sw").distance(&HexPoint::origin()), 0);
+        assert_eq!(walk("ne,ne,s,s").distance(&HexPoint::origin()), 2);
+        assert_eq!(walk("se,sw,se,sw,sw").distance(&HexPoint::origin()), 3);
+        assert_eq!(HexPoint::of_cube(1, 2, -3), Some(HexPoint::new(1, 2)));
+        assert_eq!(HexPoint::of_cube(1, 2, 3), None);
+        assert_eq!(HexPoint::new(1, 2).cube(), (1, 2, -3));
+    }
+
+    #[test]
+    fn hex_ring_test() {
+        let center = HexPoint::new(2, -1);
+        assert_eq!(center.ring(0).collect::<Vec<_>>(), vec![center]);
+        let mut ring = center.ring(1).collect::<Vec<_>>();
+        assert_eq!(ring[0], center + HexDirection::North);
+        ring.sort_by_key(|point| (point.q, point.r));
+        let mut neighbors = center.neighbors().to_vec();
+        neighbors.sort_by_key(|point| (point.q, point.r));
+        assert_eq!(ring, neighbors);
+        for radius in 1..5 {
+            let ring = center.ring(radius).collect::<HashSet<_>>();
+            assert_eq!(ring.len(), 6 * radius);
+            assert!(ring.iter().all(|point| point.distance(&center) == radius));
+        }
+        assert_eq!(center.spiral(3).count(), 37);
+    }
+
+    #[test]
+    fn hex_lattice_test() {
+        let mut lattice = HexLattice::from([(HexPoint::origin(), 'a')]);
+        lattice.set(HexPoint::origin() + HexDirection::South, 'b');
+        lattice.set(HexPoint::new(5, 5), 'c');
+        assert_eq!(
+            lattice.neighbors(HexPoint::origin()).collect::<Vec<_>>(),
+            vec![(HexPoint::new(0, 1), &'b')]
+        );
+        *lattice.entry(HexPoint::new(5, 5)).or_insert('z') = 'd';
+        assert_eq!(lattice[HexPoint::new(5, 5)], 'd');
+        assert_eq!(lattice.remove(HexPoint::origin()), Some('a'));
+        assert_eq!(lattice.len(), 2);
+    }
+
+    #[test]
+    fn bounded_toroidal_topology_test() {
+        let dimensions = GridDimensions::new(1, 3, 0, 4);
+        let bounded = Bounded::new(dimensions);
+        assert_eq!(bounded.step(GridPoint::new(1, 3), Direction::East), None);
+        assert_eq!(
+            bounded
+                .traverse(GridPoint::new(1, 1), Direction::East)
+                .map(|(point, _)| point)
+                .collect::<Vec<_>>(),
+            vec![
+                GridPoint::new(1, 1),
+                GridPoint::new(1, 2),
+                GridPoint::new(1, 3)
+            ]
+        );
+        let torus = Toroidal::new(dimensions);
+        assert_eq!(
+            torus.step(GridPoint::new(1, 3), Direction::East),
+            Some((GridPoint::new(1, 0), Direction::East))
+        );
+        assert_eq!(
+            torus.step(GridPoint::new(1, 2), Direction::North),
+            Some((GridPoint::new(2, 2), Direction::North))
+        );
+        assert_eq!(torus.step(GridPoint::new(0, 2), Direction::North), None);
+    }
+
+    const MONKEY_MAP: [&str; 12] = [
+        "        ...#    ",
+        "        .#..    ",
+        "        #...    ",
+        "        ....    ",
+        "...#.......#    ",
+        "........#...    ",
+        "..#....#....    ",
+        "..........#.    ",
+        "        ...#....",
+        "        .....#..",
+        "        .#......",
+        "        ......#.",
+    ];
+
+    fn monkey_map() -> Grid<char> {
+        Grid::of_vec_of_vecs(MONKEY_MAP.iter().map(|row| row.chars().collect()).collect()).unwrap()
+    }
+
+    // follows the path, stopping at walls, and returns the final password
+    fn walk_monkey_map<Top: Topology>(topology: &Top) -> usize {
+        let map = monkey_map();
+        let mut position = (GridPoint::new(0, 8), Direction::East);
+        for (steps, turn) in [
+            (10, 'R'),
+            (5, 'L'),
+            (5, 'R'),
+            (10, 'L'),
+            (4, 'R'),
+            (5, 'L'),
+            (5, ' '),
+        ] {
+            position = topology
+                .traverse(position.0, position.1)
+                .take(steps + 1)
+                .take_while(|(point, _)| map[*point] != '#')
+                .last()
+                .unwrap();
+            position.1 = match turn {
+                'R' => position.1.rotate_right(),
+                'L' => position.1.rotate_left(),
+                _ => position.1,
+            };
+        }
+        let facing = match position.1 {
+            Direction::East => 0,
+            Direction::South => 1,
+            Direction::West => 2,
+            Direction::North => 3,
+        };
+        1000 * (position.0.row + 1) + 4 * (position.0.col + 1) + facing
+    }
+
+    #[test]
+    fn void_wrap_topology_test() {
+        let topology = VoidWrap::new(&monkey_map(), |c| *c == ' ');
+        assert_eq!(
+            topology.step(GridPoint::new(6, 0), Direction::West),
+            Some((GridPoint::new(6, 11), Direction::West))
+        );
+        assert_eq!(
+            topology.step(GridPoint::new(7, 5), Direction::South),
+            Some((GridPoint::new(4, 5), Direction::South))
+        );
+        assert_eq!(walk_monkey_map(&topology), 6032);
+    }
+
+    #[test]
+    fn cube_net_topology_test() {
+        let map = monkey_map();
+        let cube = CubeNet::new(&map, |c| *c == ' ').unwrap();
+        assert_eq!(
+            cube.step(GridPoint::new(5, 11), Direction::East),
+            Some((GridPoint::new(8, 14), Direction::South))
+        );
+        assert_eq!(
+            cube.step(GridPoint::new(11, 10), Direction::South),
+            Some((GridPoint::new(7, 1), Direction::North))
+        );
+        assert_eq!(
+            cube.step(GridPoint::new(5, 5), Direction::East),
+            Some((GridPoint::new(5, 6), Direction::East))
+        );
+        assert_eq!(cube.step(GridPoint::new(0, 0), Direction::East), None);
+        // stepping over any edge and turning around leads straight back
+        for point in map.iter_points().filter(|point| map[*point] != ' ') {
+            for direction in Direction::all() {
+                let (next, facing) = cube.step(point, direction).unwrap();
+                assert_eq!(cube.step(next, -facing), Some((point, -direction)));
+            }
+        }
+        assert_eq!(walk_monkey_map(&cube), 5031);
+        assert_eq!(CubeNet::new(&small_grid(), |_| false), None);
+    }
+
+    #[test]
+    fn direction_test() {
+        for direction in Direction::all() {
+            assert_eq!(direction.turn(Turn::Left).turn(Turn::Right), direction);
+            assert_eq!(direction.turn(Turn::Back), -direction);
+            assert_eq!(Direction::from_delta(direction.into()), Some(direction));
+            assert_eq!(Direction::of_arrow(direction.arrow()), Some(direction));
+            assert_eq!(Direction::of_compass(direction.compass()), Some(direction));
+            assert_eq!(Direction::of_udlr(direction.udlr()), Some(direction));
+        }
+        assert_eq!(Direction::all().map(GridPointDelta::from), PLUS_ADJACENT);
+        assert_eq!(Direction::from_delta(GridPointDelta::new(2, 0)), None);
+        assert_eq!(Direction::of_arrow('x'), None);
+        assert_eq!(Direction::of_compass('L'), None);
+        assert_eq!(Direction::of_udlr('N'), None);
+        assert_eq!(Turn::of_char('R'), Some(Turn::Right));
+    }
+
+    #[test]
+    fn direction8_test() {
+        assert_eq!(Direction8::all().map(GridPointDelta::from), ADJACENT);
+        for direction in Direction8::all() {
+            assert_eq!(direction.rotate_left().rotate_right(), direction);
+            assert_eq!(
+                GridPointDelta::from(-direction),
+                -GridPointDelta::from(direction)
+            );
+            assert_eq!(Direction8::from_delta(direction.into()), Some(direction));
+        }
+        assert_eq!(Direction8::North.rotate_right(), Direction8::NorthEast);
+        assert_eq!(
+            Direction8::NorthWest.turn(Turn::Right),
+            Direction8::NorthEast
+        );
+        assert!(Direction8::SouthWest.is_diagonal());
+        assert_eq!(Direction::try_from(Direction8::West), Ok(Direction::West));
+        assert_eq!(
+            Direction::try_from(Direction8::SouthEast),
+            Err(Direction8::SouthEast)
+        );
+        assert_eq!(
+            Direction8::from(Direction::East) * 3,
+            GridPointDelta::new(0, 3)
+        );
+    }
+
+    #[test]
+    fn grid_point_safe_arithmetic_test() {
+        let point: GridPoint<usize> = GridPoint::new(0, 2);
+        assert_eq!(point.checked_add(WEST), Some(GridPoint::new(0, 1)));
+        assert_eq!(point.checked_add(NORTH), None);
+        assert_eq!(point.try_step(Direction::North), None);
+        assert_eq!(point.try_step(Direction::South), Some(GridPoint::new(1, 2)));
+        assert_eq!(
+            GridPoint::new(isize::MAX, 0).checked_add(EAST * 1),
+            Some(GridPoint::new(isize::MAX, 1))
+        );
+        assert_eq!(GridPoint::new(isize::MAX, 0).checked_add(SOUTH), None);
+
+        let dimensions = GridDimensions::new(0, 3, 1, 5);
+        assert_eq!(
+            point.wrapping_add(NORTH, &dimensions),
+            Some(GridPoint::new(2, 2))
+        );
+        assert_eq!(
+            point.wrapping_add(GridPointDelta::new(7, -6), &dimensions),
+            Some(GridPoint::new(1, 4))
+        );
+        assert_eq!(
+            point.saturating_add(GridPointDelta::new(-5, 10), &dimensions),
+            Some(GridPoint::new(0, 4))
+        );
+        assert_eq!(
+            point.saturating_add(WEST * 2, &dimensions),
+            Some(GridPoint::new(0, 1))
+        );
+
+        let empty = GridDimensions::new(0, 0, 1, 5);
+        assert_eq!(point.wrapping_add(NORTH, &empty), None);
+        assert_eq!(point.saturating_add(NORTH, &empty), None);
+
+        let huge: GridPointDelta<u128> = GridPointDelta::new(u128::MAX, 0);
+        assert_eq!(point.checked_add(huge), None);
+        assert_eq!(point.wrapping_add(huge, &dimensions), None);
+        assert_eq!(point.saturating_add(huge, &dimensions), None);
+    }
+
+    #[test]
+    fn grid_lattice_interop_test() {
+        let point: GridPoint<usize> = GridPoint::new(1, 2);
+        assert_eq!(point.signed(), Some(GridPoint::new(1, 2)));
+        assert_eq!(point.signed().and_then(GridPoint::unsigned), Some(point));
+        assert_eq!(GridPoint::new(usize::MAX, 0).signed(), None);
+        assert_eq!(GridPoint::new(-1, 2).unsigned(), None);
+
+        let lattice = Lattice::of_grid(small_grid());
+        assert_eq!(lattice.get(GridPoint::new(1, 2)), Some(&6));
+        assert_eq!(
+            lattice.to_grid(0),
+            Some((small_grid(), GridPoint::new(0, 0)))
+        );
+
+        let shifted = Lattice::from([(GridPoint::new(-2, 5), 'a'), (GridPoint::new(-1, 6), 'b')]);
+        assert_eq!(
+            shifted.to_grid('.'),
+            Some((
+                Grid::from(vec!['a', '.', '.', 'b'], 2, 2).unwrap(),
+                GridPoint::new(-2, 5)
+            ))
+        );
+        assert_eq!(Lattice::<char>::empty().to_grid('.'), None);
+    }
+
+    #[test]
+    fn grid_point_metrics_test() {
+        let a: GridPoint<isize> = GridPoint::new(-1, 2);
+        let b = GridPoint::new(3, -1);
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.squared_euclidean_distance(&b), Some(25));
+        assert_eq!(
+            GridPoint::new(isize::MIN, 0).squared_euclidean_distance(&GridPoint::new(0, 0)),
+            None
+        );
+        assert_eq!(
+            GridPoint::<usize>::new(0, 5).manhattan_distance(&GridPoint::new(2, 1)),
+            6
+        );
+        let delta: GridPointDelta<isize> = GridPointDelta::new(4, -3);
+        assert_eq!(delta.linf_norm(), 4);
+        assert_eq!(delta.squared_norm(), Some(25));
+        assert_eq!(GridPointDelta::new(isize::MAX, 0).squared_norm(), None);
+        assert_eq!(GridPointDelta::new(0, i32::MIN).squared_norm(), None);
+    }
+
+    #[test]
+    fn grid_point_delta_rotate_test() {
+        assert_eq!(NORTH.rotate_right(), EAST);
+        assert_eq!(NORTH.rotate_left(), WEST);
+        assert_eq!(NORTHEAST.rotate_right(), SOUTHEAST);
+        let delta: GridPointDelta<isize> = GridPointDelta::new(2, 5);
+        assert_eq!(delta.rotate_quarter_turns(4), delta);
+        assert_eq!(delta.rotate_quarter_turns(2), -delta);
+        assert_eq!(delta.rotate_quarter_turns(-1), delta.rotate_left());
+        assert_eq!(delta.rotate_quarter_turns(7), delta.rotate_left());
+    }
+
+    #[test]
+    fn manhattan_circle_test() {
+        let center: GridPoint<isize> = GridPoint::new(2, -3);
+        assert_eq!(center.manhattan_circle(0).collect::<Vec<_>>(), vec![center]);
+        assert_eq!(
+            center.manhattan_circle(1).collect::<Vec<_>>(),
+            PLUS_ADJACENT.map(|delta| center + delta).to_vec()
+        );
+        for radius in 1..6 {
+            let circle = center.manhattan_circle(radius).collect::<HashSet<_>>();
+            assert_eq!(circle.len(), 4 * radius);
+            assert!(circle
+                .iter()
+                .all(|point| point.manhattan_distance(&center) == radius));
+        }
+        assert_eq!(center.manhattan_disk(3).count(), 25);
+    }
+
+    #[test]
+    fn line_to_test() {
+        let origin: GridPoint<isize> = GridPoint::new(0, 0);
+        assert_eq!(
+            origin.line_to(GridPoint::new(1, 4)).collect::<Vec<_>>(),
+            vec![
+                GridPoint::new(0, 0),
+                GridPoint::new(0, 1),
+                GridPoint::new(1, 2),
+                GridPoint::new(1, 3),
+                GridPoint::new(1, 4),
+            ]
+        );
+        assert_eq!(origin.line_to(origin).collect::<Vec<_>>(), vec![origin]);
+        for end in origin.manhattan_disk(6) {
+            let line = origin.line_to(end).collect::<Vec<_>>();
+            assert_eq!(line.len(), origin.chebyshev_distance(&end) + 1);
+            assert_eq!(line.last(), Some(&end));
+            assert!(line
+                .windows(2)
+                .all(|pair| pair[0].chebyshev_distance(&pair[1]) == 1));
+            let mut back = end.line_to(origin).collect::<Vec<_>>();
+            back.reverse();
+            assert_eq!(back.len(), line.len());
+        }
+    }
+
+    #[test]
+    fn polygon_test() {
+        let triangle = Polygon::new([
+            GridPoint::new(0, 0),
+            GridPoint::new(0, 4),
+            GridPoint::new(3, 0),
+        ]);
+        assert_eq!(triangle.signed_doubled_area(), 12);
+        assert_eq!(triangle.area(), num::rational::Ratio::new(6, 1));
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), Some(3));
+        assert_eq!(triangle.enclosed_points(), Some(11));
+
+        let reversed = Polygon::new(triangle.vertices().iter().rev().copied());
+        assert_eq!(reversed.signed_doubled_area(), -12);
+        assert_eq!(reversed.interior_points(), Some(3));
+
+        let sliver = Polygon::new([
+            GridPoint::new(0, 0),
+            GridPoint::new(1, 1),
+            GridPoint::new(0, 1),
+        ]);
+        assert_eq!(sliver.area(), num::rational::Ratio::new(1, 2));
+        assert_eq!(sliver.interior_points(), Some(0));
+
+        assert_eq!(Polygon::new([]).interior_points(), None);
+        assert_eq!(Polygon::new([]).enclosed_points(), None);
+        let segment = Polygon::new([GridPoint::new(0, 0), GridPoint::new(0, 5)]);
+        assert_eq!(segment.boundary_points(), 10);
+        assert_eq!(segment.interior_points(), None);
+        let collinear = Polygon::new([
+            GridPoint::new(0, 0),
+            GridPoint::new(2, 2),
+            GridPoint::new(4, 4),
+        ]);
+        assert_eq!(collinear.interior_points(), None);
+    }
+
+    #[test]
+    fn polygon_path_test() {
+        // the example dig plan, both with its small and its hex encoded lengths
+        let plan = [
+            ('R', 6, "70c710"),
+            ('D', 5, "0dc571"),
+            ('L', 2, "5713f0"),
+            ('D', 2, "d2c081"),
+            ('R', 2, "59c680"),
+            ('D', 2, "411b91"),
+            ('L', 5, "8ceee2"),
+            ('U', 2, "caa173"),
+            ('L', 1, "1b58a2"),
+            ('U', 2, "caa171"),
+            ('R', 2, "7807d2"),
+            ('U', 3, "a77fa3"),
+            ('L', 2, "015232"),
+            ('U', 2, "7a21e3"),
+        ];
+        let small = Polygon::of_path(
+            GridPoint::new(0, 0),
+            plan.iter()
+                .map(|(d, length, _)| (Direction::of_udlr(*d).unwrap(), *length)),
+        );
+        assert_eq!(small.vertices().len(), 14);
+        assert_eq!(small.boundary_points(), 38);
+        assert_eq!(small.enclosed_points(), Some(62));
+
+        let large = Polygon::of_path(
+            GridPoint::new(0, 0),
+            plan.iter().map(|(_, _, hex)| {
+                (
+                    Direction::of_udlr(['R', 'D', 'L', 'U'][(hex.as_bytes()[5] - b'0') as usize])
+                        .unwrap(),
+                    isize::from_str_radix(&hex[..5], 16).unwrap(),
+                )
+            }),
+        );
+        assert_eq!(large.enclosed_points(), Some(952408144115));
+    }
+
+    #[test]
+    fn grid_dimensions_intersection_union_test() {
+        let a: GridDimensions<isize> = GridDimensions::new(0, 4, 0, 4);
+        let b = GridDimensions::new(2, 6, -1, 3);
+        assert_eq!(a.intersection(&b), Some(GridDimensions::new(2, 4, 0, 3)));
+        assert_eq!(a.intersection(&GridDimensions::new(4, 5, 0, 4)), None);
+        assert_eq!(a.union_bounds(&b), GridDimensions::new(0, 6, -1, 4));
+        assert_eq!(a.union_bounds(&GridDimensions::new(9, 9, 9, 10)), a);
+        assert!(GridDimensions::new(3, 3, 0, 5).is_empty());
+    }
+
+    #[test]
+    fn grid_dimensions_difference_test() {
+        let a: GridDimensions<isize> = GridDimensions::new(0, 4, 0, 4);
+        let hole = GridDimensions::new(1, 2, 1, 3);
+        let pieces = a.difference(&hole);
+        assert_eq!(
+            pieces,
+            vec![
+                GridDimensions::new(0, 1, 0, 4),
+                GridDimensions::new(2, 4, 0, 4),
+                GridDimensions::new(1, 2, 0, 1),
+                GridDimensions::new(1, 2, 3, 4),
+            ]
+        );
+        assert_eq!(
+            pieces.iter().map(|piece| piece.area()).sum::<isize>(),
+            a.area() - hole.area()
+        );
+        assert_eq!(
+            a.difference(&GridDimensions::new(2, 6, -1, 3)),
+            vec![
+                GridDimensions::new(0, 2, 0, 4),
+                GridDimensions::new(2, 4, 3, 4)
+            ]
+        );
+        assert_eq!(a.difference(&a), vec![]);
+        assert_eq!(a.difference(&GridDimensions::new(10, 11, 0, 1)), vec![a]);
+    }
+
+    #[test]
+    fn grid_dimensions_split_expand_test() {
+        let a: GridDimensions<usize> = GridDimensions::new(0, 4, 2, 5);
+        assert_eq!(
+            a.split_at_row(1),
+            (
+                GridDimensions::new(0, 1, 2, 5),
+                GridDimensions::new(1, 4, 2, 5)
+            )
+        );
+        assert_eq!(a.split_at_col(9), (a, GridDimensions::new(0, 4, 5, 5)));
+        assert_eq!(a.expand(1), None);
+        assert_eq!(
+            GridDimensions::new(1, 2, 3, 4).expand(1),
+            Some(GridDimensions::new(0, 3, 2, 5))
+        );
+    }
+
+    #[test]
+    fn grid_dimensions_corners_perimeter_test() {
+        let a: GridDimensions<usize> = GridDimensions::new(1, 4, 2, 5);
+        assert_eq!(
+            a.corners(),
+            Some([
+                GridPoint::new(1, 2),
+                GridPoint::new(1, 4),
+                GridPoint::new(3, 4),
+                GridPoint::new(3, 2),
+            ])
+        );
+        assert_eq!(
+            a.perimeter_points().collect::<Vec<_>>(),
+            vec![
+                GridPoint::new(1, 2),
+                GridPoint::new(1, 3),
+                GridPoint::new(1, 4),
+                GridPoint::new(2, 4),
+                GridPoint::new(3, 4),
+                GridPoint::new(3, 3),
+                GridPoint::new(3, 2),
+                GridPoint::new(2, 2),
+            ]
+        );
+        assert_eq!(
+            GridDimensions::new(0, 1, 0, 3).perimeter_points().count(),
+            3
+        );
+        assert_eq!(
+            GridDimensions::new(0, 3, 0, 1).perimeter_points().count(),
+            3
+        );
+        assert_eq!(
+            GridDimensions::new(0, 0, 0, 3).perimeter_points().count(),
+            0
+        );
+        assert_eq!(GridDimensions::<usize>::new(0, 0, 0, 3).corners(), None);
+        assert_eq!(a.clamp(GridPoint::new(0, 9)), Some(GridPoint::new(1, 4)));
+        assert_eq!(a.clamp(GridPoint::new(2, 3)), Some(GridPoint::new(2, 3)));
+        assert_eq!(
+            GridDimensions::<usize>::new(0, 0, 0, 3).clamp(GridPoint::new(0, 1)),
+            None
+        );
+    }
 }
@@ -0,0 +1,311 @@
+use crate::vector::Vector;
+use std::{
+    collections::HashMap,
+    ops::{Index, IndexMut},
+};
+
+////////////
+/// Nd Point
+///
+/// points in N dimensions are vectors of signed coordinates, so they can be
+/// added and subtracted like any other vector
+////////////
+
+pub type NdPoint<const N: usize> = Vector<N, isize>;
+
+////////////
+/// Nd Bounds
+///
+/// axis aligned box in N dimensions, min inclusive and max exclusive on each axis
+////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NdBounds<const N: usize> {
+    pub min: NdPoint<N>,
+    pub max: NdPoint<N>,
+}
+
+impl<const N: usize> NdBounds<N> {
+    pub fn new(min: NdPoint<N>, max: NdPoint<N>) -> Self {
+        NdBounds { min, max }
+    }
+
+    pub fn of_points_inclusive(a: NdPoint<N>, b: NdPoint<N>) -> Self {
+        NdBounds {
+            min: a.join(b, isize::min),
+            max: a.join(b, isize::max) + Vector::constant(1),
+        }
+    }
+
+    pub fn extent(&self, axis: usize) -> usize {
+        (self.max[axis] - self.min[axis]).max(0) as usize
+    }
+
+    pub fn volume(&self) -> usize {
+        (0..N).map(|axis| self.extent(axis)).product()
+    }
+
+    pub fn contains(&self, point: &NdPoint<N>) -> bool {
+        (0..N).all(|axis| self.min[axis] <= point[axis] && point[axis] < self.max[axis])
+    }
+
+    pub fn grow_to_contain(self, point: NdPoint<N>) -> Self {
+        NdBounds {
+            min: self.min.join(point, isize::min),
+            max: self.max.join(point + Vector::constant(1), isize::max),
+        }
+    }
+
+    pub fn expand(self, margin: isize) -> Self {
+        NdBounds {
+            min: self.min - Vector::constant(margin),
+            max: self.max + Vector::constant(margin),
+        }
+    }
+
+    // every contained point, with the last axis changing fastest
+    pub fn iter(&self) -> NdBoundsIterator<N> {
+        NdBoundsIterator {
+            bounds: *self,
+            next: (self.volume() > 0).then_some(self.min),
+        }
+    }
+
+    // position of a contained point in a dense array laid out in iteration order
+    fn offset_of(&self, point: &NdPoint<N>) -> Option<usize> {
+        if !self.contains(point) {
+            return None;
+        }
+        Some((0..N).fold(0, |offset, axis| {
+            offset * self.extent(axis) + (point[axis] - self.min[axis]) as usize
+        }))
+    }
+}
+
+pub struct NdBoundsIterator<const N: usize> {
+    bounds: NdBounds<N>,
+    next: Option<NdPoint<N>>,
+}
+
+impl<const N: usize> Iterator for NdBoundsIterator<N> {
+    type Item = NdPoint<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        let mut next = current;
+        self.next = None;
+        for axis in (0..N).rev() {
+            next[axis] += 1;
+            if next[axis] < self.bounds.max[axis] {
+                self.next = Some(next);
+                break;
+            }
+            next[axis] = self.bounds.min[axis];
+        }
+        Some(current)
+    }
+}
+
+////////////
+/// Nd Neighborhood
+///
+/// VonNeumann is the 2N points one step along a single axis,
+/// Moore is all 3^N - 1 points at most one step along every axis
+////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NdNeighborhood<const N: usize> {
+    VonNeumann,
+    Moore,
+    Custom(Vec<NdPoint<N>>),
+}
+
+impl<const N: usize> NdNeighborhood<N> {
+    pub fn deltas(&self) -> Vec<NdPoint<N>> {
+        match self {
+            Self::VonNeumann => (0..N)
+                .flat_map(|axis| {
+                    [-1, 1].map(|step| {
+                        let mut delta = Vector::constant(0);
+                        delta[axis] = step;
+                        delta
+                    })
+                })
+                .collect(),
+            Self::Moore => NdBounds::new(Vector::constant(-1), Vector::constant(2))
+                .iter()
+                .filter(|delta| *delta != Vector::constant(0))
+                .collect(),
+            Self::Custom(deltas) => deltas.clone(),
+        }
+    }
+}
+
+////////////
+/// Nd Grid
+///
+/// dense storage over a fixed box, which may start at negative coordinates
+////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NdGrid<const N: usize, T> {
+    bounds: NdBounds<N>,
+    cells: Vec<T>,
+}
+
+impl<const N: usize, T: Clone> NdGrid<N, T> {
+    pub fn init(init: T, bounds: NdBounds<N>) -> Self {
+        NdGrid {
+            bounds,
+            cells: vec![init; bounds.volume()],
+        }
+    }
+
+    // the bounding box of the lattice, with default for any missing points
+    pub fn of_lattice(lattice: &NdLattice<N, T>, default: T) -> Option<Self> {
+        let mut grid = Self::init(default, lattice.bounding_box()?);
+        for (point, value) in lattice.iter() {
+            grid[*point] = value.clone();
+        }
+        Some(grid)
+    }
+}
+
+impl<const N: usize, T> NdGrid<N, T> {
+    pub fn bounds(&self) -> NdBounds<N> {
+        self.bounds
+    }
+
+    pub fn iter_points(&self) -> NdBoundsIterator<N> {
+        self.bounds.iter()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (NdPoint<N>, &T)> {
+        self.bounds.iter().zip(self.cells.iter())
+    }
+
+    pub fn get(&self, point: NdPoint<N>) -> Option<&T> {
+        self.cells.get(self.bounds.offset_of(&point)?)
+    }
+
+    pub fn get_mut(&mut self, point: NdPoint<N>) -> Option<&mut T> {
+        self.cells.get_mut(self.bounds.offset_of(&point)?)
+    }
+
+    pub fn set(&mut self, point: NdPoint<N>, value: T) -> Option<T> {
+        Some(std::mem::replace(self.get_mut(point)?, value))
+    }
+
+    // only neighbors inside the bounds are returned
+    pub fn neighbors(
+        &self,
+        point: NdPoint<N>,
+        neighborhood: &NdNeighborhood<N>,
+    ) -> impl Iterator<Item = (NdPoint<N>, &T)> {
+        neighborhood.deltas().into_iter().filter_map(move |delta| {
+            let neighbor = point + delta;
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+}
+
+impl<const N: usize, T> Index<NdPoint<N>> for NdGrid<N, T> {
+    type Output = T;
+
+    fn index(&self, index: NdPoint<N>) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<const N: usize, T> IndexMut<NdPoint<N>> for NdGrid<N, T> {
+    fn index_mut(&mut self, index: NdPoint<N>) -> &mut Self::Output {
+        self.get_mut(index).unwrap()
+    }
+}
+
+////////////
+/// Nd Lattice
+///
+/// sparse storage over all of N dimensional space
+////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NdLattice<const N: usize, T> {
+    points: HashMap<NdPoint<N>, T>,
+}
+
+impl<const N: usize, T> NdLattice<N, T> {
+    pub fn empty() -> Self {
+        NdLattice {
+            points: HashMap::new(),
+        }
+    }
+
+    pub fn from<I: IntoIterator<Item = (NdPoint<N>, T)>>(iter: I) -> Self {
+        NdLattice {
+            points: iter.into_iter().collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn contains(&self, point: NdPoint<N>) -> bool {
+        self.points.contains_key(&point)
+    }
+
+    pub fn get(&self, point: NdPoint<N>) -> Option<&T> {
+        self.points.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: NdPoint<N>) -> Option<&mut T> {
+        self.points.get_mut(&point)
+    }
+
+    pub fn set(&mut self, point: NdPoint<N>, value: T) -> Option<T> {
+        self.points.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: NdPoint<N>) -> Option<T> {
+        self.points.remove(&point)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&NdPoint<N>, &T)> {
+        self.points.iter()
+    }
+
+    pub fn bounding_box(&self) -> Option<NdBounds<N>> {
+        let mut points = self.points.keys();
+        let first = *points.next()?;
+        Some(points.fold(
+            NdBounds::of_points_inclusive(first, first),
+            |bounds, point| bounds.grow_to_contain(*point),
+        ))
+    }
+
+    // only neighbors which are present in the lattice are returned
+    pub fn neighbors(
+        &self,
+        point: NdPoint<N>,
+        neighborhood: &NdNeighborhood<N>,
+    ) -> impl Iterator<Item = (NdPoint<N>, &T)> {
+        neighborhood.deltas().into_iter().filter_map(move |delta| {
+            let neighbor = point + delta;
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+}
+
+impl<const N: usize, T> IntoIterator for NdLattice<N, T> {
+    type Item = (NdPoint<N>, T);
+    type IntoIter = std::collections::hash_map::IntoIter<NdPoint<N>, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.points.into_iter()
+    }
+}
@@ -0,0 +1,57 @@
+use super::{constants, grid_point};
+
+////////////
+/// Neighborhood
+///
+/// the set of offsets considered adjacent to a point when walking a grid
+////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Neighborhood {
+    Plus,
+    Diagonal,
+    Moore,
+    Custom(Vec<grid_point::GridPointDelta<isize>>),
+}
+
+impl Neighborhood {
+    pub fn deltas(&self) -> &[grid_point::GridPointDelta<isize>] {
+        match self {
+            Self::Plus => &constants::PLUS_ADJACENT,
+            Self::Diagonal => &constants::DIAG_ADJACENT,
+            Self::Moore => &constants::ADJACENT,
+            Self::Custom(deltas) => deltas,
+        }
+    }
+}
+
+impl IntoIterator for Neighborhood {
+    type Item = grid_point::GridPointDelta<isize>;
+    type IntoIter = NeighborhoodIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Self::Plus => NeighborhoodIterator::Fixed(constants::PLUS_ADJACENT.iter()),
+            Self::Diagonal => NeighborhoodIterator::Fixed(constants::DIAG_ADJACENT.iter()),
+            Self::Moore => NeighborhoodIterator::Fixed(constants::ADJACENT.iter()),
+            Self::Custom(deltas) => NeighborhoodIterator::Custom(deltas.into_iter()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum NeighborhoodIterator {
+    Fixed(std::slice::Iter<'static, grid_point::GridPointDelta<isize>>),
+    Custom(std::vec::IntoIter<grid_point::GridPointDelta<isize>>),
+}
+
+impl Iterator for NeighborhoodIterator {
+    type Item = grid_point::GridPointDelta<isize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Fixed(iter) => iter.next().copied(),
+            Self::Custom(iter) => iter.next(),
+        }
+    }
+}
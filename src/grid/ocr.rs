@@ -0,0 +1,192 @@
+use super::{grid, grid_dimension, grid_point, lattice};
+use std::{
+    error::Error,
+    fmt::{Debug, Display},
+};
+
+////////////
+/// OCR
+///
+/// reads the block capitals some puzzles draw as their answer, in either the
+/// 6 pixel high font or the 10 pixel high font. glyphs are split on blank
+/// columns, but letters like Y fill their whole cell (5 columns for the small
+/// font, 8 for the large) and touch the next one, so wider runs are cut into
+/// cells from their left edge. each piece is trimmed, so the tables below hold
+/// each letter without padding
+////////////
+
+const SMALL_FONT: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const LARGE_FONT: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    Empty,
+    UnsupportedHeight(usize),
+    UnrecognizedGlyphs(Vec<grid_dimension::GridDimensions<usize>>),
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no lit cells to read"),
+            Self::UnsupportedHeight(height) => {
+                write!(f, "no font is {} cells high", height)
+            }
+            Self::UnrecognizedGlyphs(glyphs) => {
+                write!(f, "unrecognized glyphs at")?;
+                for glyph in glyphs {
+                    write!(f, " {}", glyph)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+pub fn ocr(grid: &grid::Grid<bool>) -> Result<String, OcrError> {
+    let lit = |row: usize, col: usize| grid[grid_point::GridPoint::new(row, col)];
+    let mut lit_rows = (0..grid.rows()).filter(|row| (0..grid.cols()).any(|col| lit(*row, col)));
+    let top = lit_rows.next().ok_or(OcrError::Empty)?;
+    let bottom = lit_rows.next_back().unwrap_or(top) + 1;
+    let (font, cell_width): (&[(char, &str)], usize) = match bottom - top {
+        6 => (&SMALL_FONT, 5),
+        10 => (&LARGE_FONT, 8),
+        height => return Err(OcrError::UnsupportedHeight(height)),
+    };
+
+    let blank_col = |col: usize| (top..bottom).all(|row| !lit(row, col));
+    let mut letters = String::new();
+    let mut unrecognized = Vec::new();
+    let mut col = 0;
+    while col < grid.cols() {
+        if blank_col(col) {
+            col += 1;
+            continue;
+        }
+        let run_start = col;
+        while col < grid.cols() && !blank_col(col) {
+            col += 1;
+        }
+        for start in (run_start..col).step_by(cell_width) {
+            let cell = start..(start + cell_width).min(col);
+            let end = cell.rev().find(|col| !blank_col(*col)).unwrap_or(start) + 1;
+            let glyph = (top..bottom)
+                .map(|row| {
+                    (start..end)
+                        .map(|col| if lit(row, col) { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            match font.iter().find(|(_, pattern)| *pattern == glyph) {
+                Some((letter, _)) => letters.push(*letter),
+                None => {
+                    unrecognized.push(grid_dimension::GridDimensions::new(top, bottom, start, end))
+                }
+            }
+        }
+    }
+
+    if unrecognized.is_empty() {
+        Ok(letters)
+    } else {
+        Err(OcrError::UnrecognizedGlyphs(unrecognized))
+    }
+}
+
+// every point present in the lattice counts as lit, glyph positions in
+// errors are relative to the top left corner of the bounding box
+pub fn ocr_lattice<T>(lattice: &lattice::Lattice<T>) -> Result<String, OcrError> {
+    let bounds = lattice.bounding_box().ok_or(OcrError::Empty)?;
+    let mut grid = grid::Grid::init(false, bounds.rows() as usize, bounds.cols() as usize);
+    for (point, _) in lattice.iter() {
+        grid[grid_point::GridPoint::new(
+            (point.row - bounds.min_row) as usize,
+            (point.col - bounds.min_col) as usize,
+        )] = true;
+    }
+    ocr(&grid)
+}
@@ -0,0 +1,255 @@
+use super::{constants, grid, grid_dimension, grid_point, lattice};
+use std::{collections::HashMap, hash::Hash};
+
+////////////
+/// Overlay
+///
+/// characters drawn over the top of a rendered grid, later marks win
+////////////
+
+#[derive(Debug, Clone)]
+pub struct Overlay<T> {
+    marks: HashMap<grid_point::GridPoint<T>, char>,
+}
+
+impl<T: Hash + Eq + Copy> Overlay<T> {
+    pub fn new() -> Self {
+        Overlay {
+            marks: HashMap::new(),
+        }
+    }
+
+    pub fn highlight<I: IntoIterator<Item = grid_point::GridPoint<T>>>(
+        mut self,
+        points: I,
+        mark: char,
+    ) -> Self {
+        self.marks
+            .extend(points.into_iter().map(|point| (point, mark)));
+        self
+    }
+
+    // each point is drawn as an arrow towards the next, steps that aren't
+    // a single orthogonal move are drawn as '*', the final point as 'X'
+    pub fn path<I: IntoIterator<Item = grid_point::GridPoint<T>>>(mut self, points: I) -> Self
+    where
+        isize: TryFrom<T> + TryInto<T>,
+    {
+        let mut points = points.into_iter().peekable();
+        while let Some(point) = points.next() {
+            let mark = match points.peek() {
+                None => 'X',
+                Some(next) => match next.sub::<isize>(point) {
+                    Some(constants::NORTH) => '^',
+                    Some(constants::EAST) => '>',
+                    Some(constants::SOUTH) => 'v',
+                    Some(constants::WEST) => '<',
+                    _ => '*',
+                },
+            };
+            self.marks.insert(point, mark);
+        }
+        self
+    }
+
+    pub fn get(&self, point: grid_point::GridPoint<T>) -> Option<char> {
+        self.marks.get(&point).copied()
+    }
+}
+
+impl<T: Hash + Eq + Copy> Default for Overlay<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////
+/// Image
+///
+/// rgb pixel buffer which can be written out as ppm or png,
+/// each cell becomes a scale x scale square of pixels
+////////////
+
+struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Image {
+    fn of_cells<F: Fn(usize, usize) -> [u8; 3]>(
+        rows: usize,
+        cols: usize,
+        scale: usize,
+        color: F,
+    ) -> Self {
+        let (width, height) = (cols * scale, rows * scale);
+        Image {
+            width,
+            height,
+            pixels: (0..width * height)
+                .map(|idx| color(idx / width / scale, idx % width / scale))
+                .collect(),
+        }
+    }
+
+    fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(self.pixels.iter().flatten());
+        bytes
+    }
+
+    // uncompressed png, zlib stored blocks keep this dependency free
+    fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+
+        let mut zlib = vec![0x78, 0x01];
+        let mut blocks = raw.chunks(u16::MAX as usize).peekable();
+        if blocks.peek().is_none() {
+            zlib.extend([1, 0, 0, 0xff, 0xff]);
+        }
+        while let Some(block) = blocks.next() {
+            let len = block.len() as u16;
+            zlib.push(blocks.peek().is_none() as u8);
+            zlib.extend(len.to_le_bytes());
+            zlib.extend((!len).to_le_bytes());
+            zlib.extend(block);
+        }
+        zlib.extend(adler32(&raw).to_be_bytes());
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut bytes = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+        png_chunk(&mut bytes, b"IHDR", &header);
+        png_chunk(&mut bytes, b"IDAT", &zlib);
+        png_chunk(&mut bytes, b"IEND", &[]);
+        bytes
+    }
+}
+
+fn png_chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend((data.len() as u32).to_be_bytes());
+    bytes.extend(kind);
+    bytes.extend(data);
+    bytes.extend(crc32(kind.iter().chain(data)).to_be_bytes());
+}
+
+fn crc32<'a, I: IntoIterator<Item = &'a u8>>(bytes: I) -> u32 {
+    !bytes.into_iter().fold(!0u32, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| {
+            (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+fn render_text<F: Fn(usize, usize) -> char>(rows: usize, cols: usize, f: F) -> String {
+    (0..rows)
+        .map(|row| (0..cols).map(|col| f(row, col)).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+////////////
+/// Grid rendering
+////////////
+
+impl<T> grid::Grid<T> {
+    // one character per cell, one line per row, no trailing newline
+    pub fn render<F: Fn(&T) -> char>(&self, f: F) -> String {
+        self.render_with_overlay(f, &Overlay::new())
+    }
+
+    pub fn render_with_overlay<F: Fn(&T) -> char>(&self, f: F, overlay: &Overlay<usize>) -> String {
+        render_text(self.rows(), self.cols(), |row, col| {
+            let point = grid_point::GridPoint::new(row, col);
+            overlay.get(point).unwrap_or_else(|| f(&self[point]))
+        })
+    }
+
+    pub fn to_ppm<F: Fn(&T) -> [u8; 3]>(&self, palette: F, scale: usize) -> Vec<u8> {
+        self.image(palette, scale).to_ppm()
+    }
+
+    pub fn to_png<F: Fn(&T) -> [u8; 3]>(&self, palette: F, scale: usize) -> Vec<u8> {
+        self.image(palette, scale).to_png()
+    }
+
+    fn image<F: Fn(&T) -> [u8; 3]>(&self, palette: F, scale: usize) -> Image {
+        Image::of_cells(self.rows(), self.cols(), scale, |row, col| {
+            palette(&self[grid_point::GridPoint::new(row, col)])
+        })
+    }
+}
+
+////////////
+/// Lattice rendering
+///
+/// covers the bounding box, so f is also called for points that aren't set
+////////////
+
+impl<T> lattice::Lattice<T> {
+    pub fn render<F: Fn(Option<&T>) -> char>(&self, f: F) -> String {
+        self.render_with_overlay(f, &Overlay::new())
+    }
+
+    pub fn render_with_overlay<F: Fn(Option<&T>) -> char>(
+        &self,
+        f: F,
+        overlay: &Overlay<isize>,
+    ) -> String {
+        let Some(bounds) = self.bounding_box() else {
+            return String::new();
+        };
+        render_text(
+            bounds.rows() as usize,
+            bounds.cols() as usize,
+            |row, col| {
+                let point = grid_point::GridPoint::new(
+                    bounds.min_row + row as isize,
+                    bounds.min_col + col as isize,
+                );
+                overlay.get(point).unwrap_or_else(|| f(self.get(point)))
+            },
+        )
+    }
+
+    pub fn to_ppm<F: Fn(Option<&T>) -> [u8; 3]>(&self, palette: F, scale: usize) -> Vec<u8> {
+        self.image(palette, scale).to_ppm()
+    }
+
+    pub fn to_png<F: Fn(Option<&T>) -> [u8; 3]>(&self, palette: F, scale: usize) -> Vec<u8> {
+        self.image(palette, scale).to_png()
+    }
+
+    fn image<F: Fn(Option<&T>) -> [u8; 3]>(&self, palette: F, scale: usize) -> Image {
+        let bounds = self
+            .bounding_box()
+            .unwrap_or(grid_dimension::GridDimensions::new(0, 0, 0, 0));
+        Image::of_cells(
+            bounds.rows() as usize,
+            bounds.cols() as usize,
+            scale,
+            |row, col| {
+                palette(self.get(grid_point::GridPoint::new(
+                    bounds.min_row + row as isize,
+                    bounds.min_col + col as isize,
+                )))
+            },
+        )
+    }
+}
@@ -0,0 +1,342 @@
+use super::{direction, grid, grid_dimension, grid_point};
+use std::collections::{HashMap, VecDeque};
+
+////////////
+/// Topology
+///
+/// decides where a single step in a direction leads, and which way you
+/// are facing afterwards, so that movement code doesn't need to know
+/// what happens at the edges of the grid
+////////////
+
+pub trait Topology {
+    // None if the step would leave the space entirely
+    fn step(
+        &self,
+        point: grid_point::GridPoint<usize>,
+        direction: direction::Direction,
+    ) -> Option<(grid_point::GridPoint<usize>, direction::Direction)>;
+
+    // the starting point followed by each step taken from it
+    fn traverse(
+        &self,
+        point: grid_point::GridPoint<usize>,
+        direction: direction::Direction,
+    ) -> impl Iterator<Item = (grid_point::GridPoint<usize>, direction::Direction)> + '_ {
+        std::iter::successors(Some((point, direction)), |(point, direction)| {
+            self.step(*point, *direction)
+        })
+    }
+}
+
+fn wrap(value: usize, delta: isize, min: usize, len: usize) -> usize {
+    min + (value as isize - min as isize + delta).rem_euclid(len as isize) as usize
+}
+
+////////////
+/// Bounded
+///
+/// stepping off the edge fails
+////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounded {
+    dimensions: grid_dimension::GridDimensions<usize>,
+}
+
+impl Bounded {
+    pub fn new(dimensions: grid_dimension::GridDimensions<usize>) -> Self {
+        Bounded { dimensions }
+    }
+}
+
+impl Topology for Bounded {
+    fn step(
+        &self,
+        point: grid_point::GridPoint<usize>,
+        direction: direction::Direction,
+    ) -> Option<(grid_point::GridPoint<usize>, direction::Direction)> {
+        Some((
+            point.add_checked(direction.into(), &self.dimensions)?,
+            direction,
+        ))
+    }
+}
+
+////////////
+/// Toroidal
+///
+/// stepping off one edge comes back on at the opposite edge
+////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Toroidal {
+    dimensions: grid_dimension::GridDimensions<usize>,
+}
+
+impl Toroidal {
+    pub fn new(dimensions: grid_dimension::GridDimensions<usize>) -> Self {
+        Toroidal { dimensions }
+    }
+}
+
+impl Topology for Toroidal {
+    fn step(
+        &self,
+        point: grid_point::GridPoint<usize>,
+        direction: direction::Direction,
+    ) -> Option<(grid_point::GridPoint<usize>, direction::Direction)> {
+        if !self.dimensions.contains(&point) {
+            return None;
+        }
+        let delta = grid_point::GridPointDelta::<isize>::from(direction);
+        Some((
+            grid_point::GridPoint::new(
+                wrap(
+                    point.row,
+                    delta.row_delta,
+                    self.dimensions.min_row,
+                    self.dimensions.rows(),
+                ),
+                wrap(
+                    point.col,
+                    delta.col_delta,
+                    self.dimensions.min_col,
+                    self.dimensions.cols(),
+                ),
+            ),
+            direction,
+        ))
+    }
+}
+
+////////////
+/// Void Wrap
+///
+/// toroidal wrapping along the current row or column, where void cells
+/// are skipped over as if they weren't there
+////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoidWrap {
+    void: grid::Grid<bool>,
+}
+
+impl VoidWrap {
+    pub fn new<T, F: Fn(&T) -> bool>(grid: &grid::Grid<T>, is_void: F) -> Self {
+        VoidWrap {
+            void: grid::Grid::from(
+                grid.iter_points().map(|point| is_void(&grid[point])),
+                grid.rows(),
+                grid.cols(),
+            )
+            .unwrap(),
+        }
+    }
+}
+
+impl Topology for VoidWrap {
+    fn step(
+        &self,
+        point: grid_point::GridPoint<usize>,
+        direction: direction::Direction,
+    ) -> Option<(grid_point::GridPoint<usize>, direction::Direction)> {
+        let torus = Toroidal::new(self.void.dimensions());
+        let line_length = match direction {
+            direction::Direction::North | direction::Direction::South => self.void.rows(),
+            direction::Direction::East | direction::Direction::West => self.void.cols(),
+        };
+        let mut next = point;
+        for _ in 0..line_length {
+            next = torus.step(next, direction)?.0;
+            if !self.void[next] {
+                return Some((next, direction));
+            }
+        }
+        None
+    }
+}
+
+////////////
+/// Cube Net
+///
+/// the non void cells of the grid are six square faces which fold up into a cube.
+/// each face is given a frame in 3d, its outward normal along with the
+/// directions east and south on the grid point in, found by folding outwards
+/// from the first face. stepping off a face lands on the face whose normal
+/// matches the way we were heading, travelling away from the face we left
+////////////
+
+type Vec3 = [isize; 3];
+
+fn scale(v: Vec3, k: isize) -> Vec3 {
+    v.map(|x| x * k)
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn dot(a: Vec3, b: Vec3) -> isize {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Face {
+    origin: grid_point::GridPoint<usize>,
+    normal: Vec3,
+    east: Vec3,
+    south: Vec3,
+}
+
+impl Face {
+    fn heading(&self, direction: direction::Direction) -> Vec3 {
+        match direction {
+            direction::Direction::North => scale(self.south, -1),
+            direction::Direction::East => self.east,
+            direction::Direction::South => self.south,
+            direction::Direction::West => scale(self.east, -1),
+        }
+    }
+
+    // the face on the other side of the given edge once folded
+    fn fold(&self, direction: direction::Direction, origin: grid_point::GridPoint<usize>) -> Self {
+        let heading = self.heading(direction);
+        let (east, south) = match direction {
+            direction::Direction::North | direction::Direction::South => {
+                (self.east, scale(self.normal, -dot(heading, self.south)))
+            }
+            direction::Direction::East | direction::Direction::West => {
+                (scale(self.normal, -dot(heading, self.east)), self.south)
+            }
+        };
+        Face {
+            origin,
+            normal: heading,
+            east,
+            south,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CubeNet {
+    size: usize,
+    dimensions: grid_dimension::GridDimensions<usize>,
+    faces: HashMap<grid_point::GridPoint<usize>, Face>,
+}
+
+impl CubeNet {
+    // None if the non void cells don't form six faces which fold into a cube
+    pub fn new<T, F: Fn(&T) -> bool>(grid: &grid::Grid<T>, is_void: F) -> Option<Self> {
+        let area = grid
+            .iter_points()
+            .filter(|point| !is_void(&grid[*point]))
+            .count();
+        let size = (1..=grid.rows().max(grid.cols())).find(|size| 6 * size * size == area)?;
+        let blocks = (0..grid.rows() / size)
+            .flat_map(|row| {
+                (0..grid.cols() / size)
+                    .map(move |col| grid_point::GridPoint::new(row * size, col * size))
+            })
+            .filter(|origin| !is_void(&grid[*origin]))
+            .collect::<Vec<_>>();
+        if blocks.len() != 6 {
+            return None;
+        }
+
+        let first = Face {
+            origin: blocks[0],
+            normal: [0, 0, 1],
+            east: [1, 0, 0],
+            south: [0, 1, 0],
+        };
+        let mut faces = HashMap::from([(first.origin, first)]);
+        let mut frontier = VecDeque::from([first]);
+        let block_dimensions =
+            grid_dimension::GridDimensions::new(0, grid.rows() / size, 0, grid.cols() / size);
+        while let Some(face) = frontier.pop_front() {
+            for direction in direction::Direction::all() {
+                let Some(block) =
+                    grid_point::GridPoint::new(face.origin.row / size, face.origin.col / size)
+                        .add_checked(direction.into(), &block_dimensions)
+                else {
+                    continue;
+                };
+                let origin = grid_point::GridPoint::new(block.row * size, block.col * size);
+                if blocks.contains(&origin) && !faces.contains_key(&origin) {
+                    let next = face.fold(direction, origin);
+                    faces.insert(origin, next);
+                    frontier.push_back(next);
+                }
+            }
+        }
+
+        let mut normals = faces.values().map(|face| face.normal).collect::<Vec<_>>();
+        normals.sort();
+        normals.dedup();
+        if normals.len() != 6 {
+            return None;
+        }
+        Some(CubeNet {
+            size,
+            dimensions: grid.dimensions(),
+            faces,
+        })
+    }
+
+    fn face_of(&self, point: grid_point::GridPoint<usize>) -> Option<&Face> {
+        self.faces.get(&grid_point::GridPoint::new(
+            point.row - point.row % self.size,
+            point.col - point.col % self.size,
+        ))
+    }
+
+    // cell centers on the surface of a cube from -size to size on each axis,
+    // doubled so that they stay whole numbers
+    fn to_cube(&self, face: &Face, point: grid_point::GridPoint<usize>) -> Vec3 {
+        let size = self.size as isize;
+        let local_row = (point.row - face.origin.row) as isize;
+        let local_col = (point.col - face.origin.col) as isize;
+        add(
+            scale(face.normal, size),
+            add(
+                scale(face.east, 2 * local_col + 1 - size),
+                scale(face.south, 2 * local_row + 1 - size),
+            ),
+        )
+    }
+
+    fn of_cube(&self, face: &Face, position: Vec3) -> grid_point::GridPoint<usize> {
+        let size = self.size as isize;
+        let local = add(position, scale(face.normal, -size));
+        grid_point::GridPoint::new(
+            face.origin.row + ((dot(local, face.south) + size - 1) / 2) as usize,
+            face.origin.col + ((dot(local, face.east) + size - 1) / 2) as usize,
+        )
+    }
+}
+
+impl Topology for CubeNet {
+    fn step(
+        &self,
+        point: grid_point::GridPoint<usize>,
+        direction: direction::Direction,
+    ) -> Option<(grid_point::GridPoint<usize>, direction::Direction)> {
+        let face = self.face_of(point)?;
+        if let Some(next) = point.add_checked(direction.into(), &self.dimensions) {
+            if self.face_of(next).is_some() {
+                return Some((next, direction));
+            }
+        }
+
+        let heading = face.heading(direction);
+        let next_face = self.faces.values().find(|next| next.normal == heading)?;
+        let travelling = scale(face.normal, -1);
+        let next_direction = direction::Direction::all()
+            .into_iter()
+            .find(|direction| next_face.heading(*direction) == travelling)?;
+        let position = add(self.to_cube(face, point), add(heading, travelling));
+        Some((self.of_cube(next_face, position), next_direction))
+    }
+}
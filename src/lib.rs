@@ -1,4 +1,5 @@
 #![feature(try_trait_v2)]
+#![feature(try_trait_v2_residual)]
 #![feature(generic_const_exprs)]
 #![feature(never_type)]
 #![feature(step_trait)]
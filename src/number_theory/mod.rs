@@ -1,19 +1,44 @@
 use num::traits::Zero;
-use std::ops::Rem;
+use std::ops::{Rem, Sub};
 
 mod bezout_coefficients;
 pub mod chinese_remainder;
 pub use bezout_coefficients::bezout_coefficients;
 
+// always non-negative, negative arguments are treated as their absolute value
 pub fn gcd<T>(a: T, b: T) -> T
 where
-    T: Zero + PartialOrd + Rem<Output = T> + Clone,
+    T: Zero + PartialOrd + Rem<Output = T> + Sub<Output = T> + Clone,
 {
-    if b == T::zero() {
+    if a < T::zero() {
+        gcd(T::zero() - a, b)
+    } else if b < T::zero() {
+        gcd(a, T::zero() - b)
+    } else if b == T::zero() {
         a
     } else if a < b {
         gcd(b, a)
     } else {
-        gcd(a.clone(), b % a)
+        gcd(b.clone(), a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(gcd(12, 8), 4);
+        assert_eq!(gcd(8, 12), 4);
+        assert_eq!(gcd(42, 12), 6);
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(-4, 2), 2);
+        assert_eq!(gcd(4, -6), 2);
+        assert_eq!(gcd(-12, -8), 4);
+        assert_eq!(gcd(-5, 0), 5);
+        assert_eq!(gcd(12u32, 8u32), 4);
     }
 }
@@ -1,17 +1,42 @@
 use std::convert::Infallible;
+use std::fmt::Debug;
 use std::ops::{ControlFlow, FromResidual, Try};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum ParseError {
     ParseIntError(String),
+    ParseFloatError(String),
+    Overflow(String, &'static str),
     UnexpectedChar(char),
     UnmatchedTag(String),
     Generic(String),
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    UnmappedChar {
+        found: char,
+        row: usize,
+        col: usize,
+    },
+    // line is 1-indexed, like editors and the trace output
+    AtLine {
+        line: usize,
+        error: Box<ParseError>,
+    },
     RemainingUnparsed,
+    UnexpectedMatch,
     XorBothTrue,
     EndOfString,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum ParseState<'a, T> {
     Err { error: ParseError, rest: &'a str },
@@ -116,6 +141,10 @@ impl<'a, T> FromResidual<ParseState<'a, Infallible>> for ParseState<'a, T> {
     }
 }
 
+impl<'a, T> std::ops::Residual<(T, &'a str)> for ParseState<'a, Infallible> {
+    type TryType = ParseState<'a, T>;
+}
+
 impl<'a, T> Try for ParseState<'a, T> {
     type Output = (T, &'a str);
     type Residual = ParseState<'a, Infallible>;
@@ -162,14 +191,23 @@ impl<'a, T> ParseState<'a, T> {
 
 mod parsers_internal {
     use std::{
+        any::type_name,
+        cell::OnceCell,
         collections::{HashMap, VecDeque},
+        fmt::Display,
         hash::Hash,
         marker::PhantomData,
-        ops::Neg,
+        num::{IntErrorKind, ParseIntError},
+        rc::{Rc, Weak},
         str::FromStr,
+        thread,
     };
 
-    use super::{super::grid, parsers, ParseError, ParseState, Parser};
+    use num::{rational::Ratio, Integer, Num};
+
+    use super::{
+        super::grid, parsers, trace, Associativity, ParseError, ParseState, Parser, Printer,
+    };
 
     ////////
     ///
@@ -302,6 +340,42 @@ mod parsers_internal {
         }
     }
 
+    // StrMap
+    //
+    // like CharMap over the longest prefix of at most max_len chars which f accepts
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    pub struct StrMap<T, F: Fn(&str) -> Option<T>> {
+        max_len: usize,
+        f: F,
+    }
+
+    impl<T, F: Fn(&str) -> Option<T>> StrMap<T, F> {
+        pub fn new(max_len: usize, f: F) -> Self {
+            StrMap { max_len, f }
+        }
+    }
+
+    impl<T, F: Fn(&str) -> Option<T>> Parser for StrMap<T, F> {
+        type Output = T;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            let ends = s
+                .char_indices()
+                .map(|(idx, c)| idx + c.len_utf8())
+                .take(self.max_len)
+                .collect::<Vec<usize>>();
+            for end in ends.into_iter().rev() {
+                if let Some(result) = (self.f)(&s[..end]) {
+                    return ParseState::ok(result, &s[end..]);
+                }
+            }
+            match s.chars().next() {
+                None => ParseState::error_end_of_string(s),
+                Some(c) => ParseState::error_unexpected_char(c, s),
+            }
+        }
+    }
+
     // TagReplace
     #[derive(Debug, PartialEq, Eq, Clone, Copy)]
     pub struct TagReplace<'a, T>(&'a str, T);
@@ -611,6 +685,14 @@ mod parsers_internal {
         }
     }
 
+    impl<T> FromIterator<T> for ManyIter<T> {
+        fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+            ManyIter {
+                contents: iter.into_iter().collect(),
+            }
+        }
+    }
+
     #[derive(Debug, PartialEq, Eq, Clone, Copy)]
     pub struct Many<P> {
         p: P,
@@ -726,6 +808,26 @@ mod parsers_internal {
     }
 
     // Number
+    fn number_error<T, E: 'static>(err: E, s: String) -> ParseError {
+        match (&err as &dyn std::any::Any)
+            .downcast_ref::<ParseIntError>()
+            .map(|err| err.kind())
+        {
+            Some(IntErrorKind::PosOverflow | IntErrorKind::NegOverflow) => {
+                ParseError::Overflow(s, type_name::<T>())
+            }
+            _ => ParseError::ParseIntError(s),
+        }
+    }
+
+    fn number_of_str<T>(s: String) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: 'static,
+    {
+        s.parse::<T>().map_err(|err| number_error::<T, _>(err, s))
+    }
+
     #[derive(Debug, PartialEq, Eq, Clone, Copy)]
     pub struct Number<'a, T>(&'a str, PhantomData<T>);
 
@@ -738,6 +840,7 @@ mod parsers_internal {
     impl<'b, T> Parser for Number<'b, T>
     where
         T: FromStr,
+        <T as FromStr>::Err: 'static,
     {
         type Output = T;
 
@@ -745,8 +848,7 @@ mod parsers_internal {
             parsers::chars(|c: char| c.is_numeric() || self.0.contains(c))
                 .many()
                 .bind(|v: ManyIter<char>| {
-                    let s = v.filter(|c: &char| c.is_numeric()).collect::<String>();
-                    s.parse::<T>().map_err(|_| ParseError::ParseIntError(s))
+                    number_of_str(v.filter(|c: &char| c.is_numeric()).collect::<String>())
                 })
                 .parse(s)
         }
@@ -764,17 +866,170 @@ mod parsers_internal {
 
     impl<'b, T> Parser for SignedNumber<'b, T>
     where
-        T: Neg<Output = T> + FromStr,
+        T: FromStr,
+        <T as FromStr>::Err: 'static,
+    {
+        type Output = T;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            // the sign is kept in the parsed string so T::MIN doesn't overflow
+            parsers::chars(|c| c == '-' || c == '+')
+                .maybe()
+                .and_then(parsers::chars(|c: char| c.is_numeric() || self.0.contains(c)).many())
+                .bind(|(sign, v): (Option<char>, ManyIter<char>)| {
+                    number_of_str(
+                        sign.filter(|c| *c == '-')
+                            .into_iter()
+                            .chain(v.filter(|c: &char| c.is_numeric()))
+                            .collect::<String>(),
+                    )
+                })
+                .parse(s)
+        }
+    }
+
+    // Radix Number
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct RadixNumber<'a, T> {
+        radix: u32,
+        prefix: &'a str,
+        t: PhantomData<T>,
+    }
+
+    impl<'a, T> RadixNumber<'a, T> {
+        // panics unless radix is in 2..=36, like from_str_radix would on the first parse
+        pub fn new(radix: u32, prefix: &'a str) -> Self {
+            assert!(
+                (2..=36).contains(&radix),
+                "radix must be in 2..=36, got {}",
+                radix
+            );
+            RadixNumber {
+                radix,
+                prefix,
+                t: PhantomData,
+            }
+        }
+    }
+
+    impl<'b, T> Parser for RadixNumber<'b, T>
+    where
+        T: Num,
+        <T as Num>::FromStrRadixErr: 'static,
+    {
+        type Output = T;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            let radix = self.radix;
+            parsers::tag(self.prefix)
+                .maybe()
+                .ignore_and_then(parsers::many_chars(move |c: char| c.is_digit(radix)))
+                .bind(|s: String| {
+                    T::from_str_radix(&s, radix).map_err(|err| number_error::<T, _>(err, s))
+                })
+                .parse(s)
+        }
+    }
+
+    // Decimal Parts
+    //
+    // signed integer digits and optional fraction digits of a decimal number
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    struct DecimalParts;
+
+    impl Parser for DecimalParts {
+        type Output = (String, Option<String>);
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            let digits = || parsers::chars(|c: char| c.is_ascii_digit());
+            parsers::chars(|c| c == '-' || c == '+')
+                .maybe()
+                .and_then(digits().many())
+                .map(|(sign, int): (Option<char>, ManyIter<char>)| {
+                    sign.into_iter().chain(int).collect::<String>()
+                })
+                .and_then(
+                    parsers::char('.')
+                        .ignore_and_then(digits().many_at_least_one())
+                        .map(|v: ManyIter<char>| v.collect::<String>())
+                        .maybe(),
+                )
+                .parse(s)
+        }
+    }
+
+    // Float
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct Float<T>(PhantomData<T>);
+
+    impl<T> Float<T> {
+        pub fn new() -> Self {
+            Float(PhantomData)
+        }
+    }
+
+    impl<T> Parser for Float<T>
+    where
+        T: FromStr,
     {
         type Output = T;
 
         fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
-            parsers::char('-')
-                .ignore_and_then(parsers::number_with_seps(self.0))
-                .map(|n: T| -(n as T))
-                .or(parsers::char('+')
-                    .maybe()
-                    .ignore_and_then(parsers::number_with_seps(self.0)))
+            DecimalParts
+                .and_then(
+                    parsers::chars(|c| c == 'e' || c == 'E')
+                        .ignore_and_then(parsers::chars(|c| c == '-' || c == '+').maybe())
+                        .and_then(parsers::chars(|c: char| c.is_ascii_digit()).many_at_least_one())
+                        .map(|(sign, v): (Option<char>, ManyIter<char>)| {
+                            sign.into_iter().chain(v).collect::<String>()
+                        })
+                        .maybe(),
+                )
+                .bind(
+                    |((int, fraction), exponent): ((String, Option<String>), Option<String>)| {
+                        let mut s = int;
+                        if let Some(fraction) = fraction {
+                            s.push('.');
+                            s.push_str(&fraction);
+                        }
+                        if let Some(exponent) = exponent {
+                            s.push('e');
+                            s.push_str(&exponent);
+                        }
+                        s.parse::<T>().map_err(|_| ParseError::ParseFloatError(s))
+                    },
+                )
+                .parse(s)
+        }
+    }
+
+    // Decimal Fraction
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct DecimalFraction<T>(PhantomData<T>);
+
+    impl<T> DecimalFraction<T> {
+        pub fn new() -> Self {
+            DecimalFraction(PhantomData)
+        }
+    }
+
+    impl<T> Parser for DecimalFraction<T>
+    where
+        T: Integer + FromStr + Clone,
+        <T as FromStr>::Err: 'static,
+    {
+        type Output = Ratio<T>;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            DecimalParts
+                .bind(|(int, fraction): (String, Option<String>)| {
+                    let fraction = fraction.unwrap_or_default();
+                    let denominator = format!("1{}", "0".repeat(fraction.len()));
+                    Ok(Ratio::new(
+                        number_of_str(int + &fraction)?,
+                        number_of_str(denominator)?,
+                    ))
+                })
                 .parse(s)
         }
     }
@@ -863,6 +1118,86 @@ mod parsers_internal {
         }
     }
 
+    // ManyLinesPar
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct ManyLinesPar<'a, P> {
+        terminator: &'a str,
+        p: P,
+    }
+
+    impl<'a, P> ManyLinesPar<'a, P> {
+        pub fn new(terminator: &'a str, p: P) -> Self {
+            ManyLinesPar { terminator, p }
+        }
+    }
+
+    // where rest starts in s, falling back to the start of line when
+    // the inner parser handed back a rest which isn't inside it
+    fn offset_in(s: &str, line: &str, rest: &str) -> usize {
+        let line_start = line.as_ptr() as usize - s.as_ptr() as usize;
+        match (rest.as_ptr() as usize).checked_sub(line.as_ptr() as usize) {
+            Some(offset) if offset + rest.len() <= line.len() => line_start + offset,
+            _ => line_start,
+        }
+    }
+
+    impl<'b, T, P> Parser for ManyLinesPar<'b, P>
+    where
+        P: Parser<Output = T> + Clone + Send,
+        T: Send,
+    {
+        type Output = ManyIter<T>;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            let mut lines = s.split(self.terminator).collect::<Vec<&str>>();
+            // the piece after the last terminator isn't a full line
+            let rest = lines.pop().unwrap_or(s);
+            let threads = thread::available_parallelism().map_or(1, |n| n.get());
+            let chunk_size = lines.len().div_ceil(threads).max(1);
+            let results = thread::scope(|scope| {
+                lines
+                    .chunks(chunk_size)
+                    .enumerate()
+                    .map(|(chunk_idx, chunk)| {
+                        let p = self.p.clone();
+                        scope.spawn(move || {
+                            let mut parsed = Vec::with_capacity(chunk.len());
+                            for (idx, line) in chunk.iter().enumerate() {
+                                match p.clone().parse(line).finish() {
+                                    Ok(result) => parsed.push(result),
+                                    Err((error, rest)) => {
+                                        let line_idx = chunk_idx * chunk_size + idx + 1;
+                                        return Err((line_idx, error, offset_in(s, line, rest)));
+                                    }
+                                }
+                            }
+                            Ok(parsed)
+                        })
+                    })
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .collect::<Vec<_>>()
+            });
+            let mut many = ManyIter::empty();
+            for result in results {
+                match result {
+                    Ok(parsed) => parsed.into_iter().for_each(|t| many.extend(t)),
+                    Err((line, error, offset)) => {
+                        return ParseState::error(
+                            ParseError::AtLine {
+                                line,
+                                error: Box::new(error),
+                            },
+                            &s[offset..],
+                        )
+                    }
+                }
+            }
+            ParseState::ok(many, rest)
+        }
+    }
+
     // IngoreAndThen
     #[derive(Debug, PartialEq, Eq, Clone, Copy)]
     pub struct IgnoreAndThen<P, Q> {
@@ -945,231 +1280,1382 @@ mod parsers_internal {
         }
     }
 
-    ////////
-    ///
-    /// Grid related
-    ///
-    ////////
-
-    // Grid
+    // Between
     #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-    pub struct Grid<'a, 'b, P> {
-        seperator: &'a str,
-        terminator: &'b str,
+    pub struct Between<P> {
+        min: u32,
+        max: u32,
         p: P,
     }
 
-    impl<'a, 'b, P> Grid<'a, 'b, P> {
-        pub fn new(seperator: &'a str, terminator: &'b str, p: P) -> Self {
-            Grid {
-                seperator,
-                terminator,
-                p,
-            }
+    impl<P> Between<P> {
+        // panics if min > max, which could never match
+        pub fn new(min: u32, max: u32, p: P) -> Self {
+            assert!(
+                min <= max,
+                "between needs min <= max, got {} and {}",
+                min,
+                max
+            );
+            Between { min, max, p }
         }
     }
 
-    impl<'b, 'c, T, P> Parser for Grid<'b, 'c, P>
+    impl<T, P> Parser for Between<P>
     where
         P: Parser<Output = T> + Clone,
-        T: Clone,
     {
-        type Output = grid::Grid<T>;
+        type Output = ManyIter<T>;
 
         fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
-            let mut vec_of_vecs = Vec::new();
-            let (result, rest) = self
-                .p
-                .clone()
-                .list(self.seperator)
-                .skip_tag(self.terminator)
-                .parse(s)?;
-            vec_of_vecs.push(result.collect::<Vec<T>>());
-            let (result, rest) = self
-                .p
-                .clone()
-                .and_then(
-                    parsers::tag(self.seperator)
-                        .and_then(self.p.clone())
-                        .map(|(_, v)| v)
-                        .repeat((vec_of_vecs[0].len() - 1) as u32),
-                )
-                .map(|(head, mut tail): (T, ManyIter<T>)| {
-                    tail.cons(head);
-                    tail.collect::<Vec<T>>()
-                })
-                .many_lines(self.terminator)
-                .parse(rest)?;
-            vec_of_vecs.extend(result);
-            ParseState::Ok {
-                result: grid::Grid::of_vec_of_vecs(vec_of_vecs).unwrap(),
-                rest,
+            let (mut many, mut s) = self.p.clone().repeat(self.min).parse(s)?;
+            for _ in self.min..self.max {
+                match self.p.clone().parse(s) {
+                    ParseState::Ok { result, rest } => {
+                        s = rest;
+                        many.extend(result);
+                    }
+                    ParseState::Err { .. } => break,
+                }
             }
+            ParseState::ok(many, s)
         }
     }
 
-    // GridWithSpecialCells
-    pub struct GridWithSpecialCells<'a, 'b, P> {
-        seperator: &'a str,
-        terminator: &'b str,
+    // ListTrailing
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct ListTrailing<'a, P> {
+        sep: &'a str,
         p: P,
     }
 
-    impl<'a, 'b, P> GridWithSpecialCells<'a, 'b, P> {
-        pub fn new(seperator: &'a str, terminator: &'b str, p: P) -> Self {
-            GridWithSpecialCells {
-                seperator,
-                terminator,
-                p,
-            }
+    impl<'a, P> ListTrailing<'a, P> {
+        pub fn new(sep: &'a str, p: P) -> Self {
+            ListTrailing { sep, p }
         }
     }
 
-    impl<'b, 'c, T, U, P> Parser for GridWithSpecialCells<'b, 'c, P>
+    impl<'a, T, P> Parser for ListTrailing<'a, P>
     where
-        P: Parser<Output = (T, Option<U>)> + Clone,
-        U: Eq + Hash + Clone,
-        T: Clone,
+        P: Parser<Output = T> + Clone,
     {
-        type Output = (grid::Grid<T>, HashMap<U, Vec<grid::GridPoint<usize>>>);
+        type Output = ManyIter<T>;
 
-        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
-            let mut vec_of_vecs = Vec::new();
-            let mut special_points = HashMap::new();
-            let (first_row, rest) = self
-                .p
-                .clone()
-                .list(self.seperator)
-                .skip_tag(self.terminator)
-                .parse(s)?;
-            first_row
-                .clone()
-                .enumerate()
-                .filter_map(|(idx, (_, b))| b.map(|b| (idx, b)))
-                .for_each(|(col, key)| {
-                    let point = grid::GridPoint::new(0, col);
-                    special_points
-                        .entry(key)
-                        .and_modify(|v: &mut Vec<grid::GridPoint<usize>>| v.push(point))
-                        .or_insert(vec![point]);
-                });
-            vec_of_vecs.push(first_row.map(|(a, _)| a).collect::<Vec<T>>());
-            let (result, rest) = self
-                .p
-                .clone()
-                .and_then(
-                    parsers::tag(self.seperator)
-                        .and_then(self.p.clone())
-                        .map(|(_, v)| v)
-                        .repeat((vec_of_vecs[0].len() - 1) as u32),
-                )
-                .map(|(head, mut tail)| {
-                    tail.cons(head);
-                    tail
-                })
-                .many_lines(self.terminator)
-                .parse(rest)?;
-            result.clone().enumerate().for_each(|(row_m1, row)| {
-                row.enumerate()
-                    .filter_map(|(col, (_, special))| special.map(|b| (col, b)))
-                    .for_each(|(col, key)| {
-                        let point = grid::GridPoint::new(1 + row_m1, col);
-                        special_points
-                            .entry(key)
-                            .and_modify(|v: &mut Vec<grid::GridPoint<usize>>| v.push(point))
-                            .or_insert(vec![point]);
-                    })
-            });
-            vec_of_vecs.extend(result.map(|i| i.map(|(cell, _)| cell).collect::<Vec<T>>()));
-            ParseState::Ok {
-                result: (
-                    grid::Grid::of_vec_of_vecs(vec_of_vecs).unwrap(),
-                    special_points,
-                ),
-                rest,
-            }
+        fn parse<'b>(self, s: &'b str) -> ParseState<'b, Self::Output> {
+            self.p
+                .list(self.sep)
+                .skip(parsers::tag(self.sep).maybe())
+                .parse(s)
         }
     }
-}
+
+    // TakeUntil
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct TakeUntil<'a>(&'a str);
+
+    impl<'a> TakeUntil<'a> {
+        pub fn new(tag: &'a str) -> Self {
+            TakeUntil(tag)
+        }
+    }
+
+    impl<'b> Parser for TakeUntil<'b> {
+        type Output = String;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            match s.find(self.0) {
+                None => ParseState::error_unmatched_tag(self.0, s),
+                Some(idx) => ParseState::ok(s[..idx].to_owned(), &s[idx..]),
+            }
+        }
+    }
+
+    // TakeWhile1
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct TakeWhile1<F: Fn(char) -> bool>(F);
+
+    impl<F: Fn(char) -> bool + Clone> TakeWhile1<F> {
+        pub fn new(f: F) -> Self {
+            TakeWhile1(f)
+        }
+    }
+
+    impl<F: Fn(char) -> bool + Clone> Parser for TakeWhile1<F> {
+        type Output = String;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            parsers::chars(self.0)
+                .many_at_least_one()
+                .map(|v: ManyIter<char>| v.collect::<String>())
+                .parse(s)
+        }
+    }
+
+    // Not
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct Not<P>(P);
+
+    impl<P> Not<P> {
+        pub fn new(p: P) -> Self {
+            Not(p)
+        }
+    }
+
+    impl<P> Parser for Not<P>
+    where
+        P: Parser,
+    {
+        type Output = ();
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            match self.0.parse(s) {
+                ParseState::Ok { .. } => ParseState::error(ParseError::UnexpectedMatch, s),
+                ParseState::Err { .. } => ParseState::ok((), s),
+            }
+        }
+    }
+
+    // Peek
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct Peek<P>(P);
+
+    impl<P> Peek<P> {
+        pub fn new(p: P) -> Self {
+            Peek(p)
+        }
+    }
+
+    impl<T, P> Parser for Peek<P>
+    where
+        P: Parser<Output = T>,
+    {
+        type Output = T;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            self.0
+                .parse(s)
+                .and_then(|result, _| ParseState::ok(result, s))
+        }
+    }
+
+    ////////
+    ///
+    /// Recursive
+    ///
+    ////////
+
+    // Boxed
+    trait DynParser<T> {
+        fn parse_dyn<'a>(&self, s: &'a str) -> ParseState<'a, T>;
+    }
+
+    impl<T, P> DynParser<T> for P
+    where
+        P: Parser<Output = T> + Clone,
+    {
+        fn parse_dyn<'a>(&self, s: &'a str) -> ParseState<'a, T> {
+            self.clone().parse(s)
+        }
+    }
+
+    pub struct BoxedParser<'p, T>(Rc<dyn DynParser<T> + 'p>);
+
+    impl<'p, T> BoxedParser<'p, T> {
+        pub fn new<P>(p: P) -> Self
+        where
+            P: Parser<Output = T> + Clone + 'p,
+        {
+            BoxedParser(Rc::new(p))
+        }
+    }
+
+    impl<'p, T> Clone for BoxedParser<'p, T> {
+        fn clone(&self) -> Self {
+            BoxedParser(self.0.clone())
+        }
+    }
+
+    impl<'p, T> Parser for BoxedParser<'p, T> {
+        type Output = T;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            self.0.parse_dyn(s)
+        }
+    }
+
+    // Recursive
+    pub struct Recursive<'p, T>(Rc<OnceCell<BoxedParser<'p, T>>>);
+
+    impl<'p, T> Recursive<'p, T> {
+        pub fn new<P, F>(f: F) -> Self
+        where
+            P: Parser<Output = T> + Clone + 'p,
+            F: FnOnce(RecursiveRef<'p, T>) -> P,
+        {
+            let cell = Rc::new(OnceCell::new());
+            let p = f(RecursiveRef(Rc::downgrade(&cell)));
+            let _ = cell.set(BoxedParser::new(p));
+            Recursive(cell)
+        }
+    }
+
+    impl<'p, T> Clone for Recursive<'p, T> {
+        fn clone(&self) -> Self {
+            Recursive(self.0.clone())
+        }
+    }
+
+    impl<'p, T> Parser for Recursive<'p, T> {
+        type Output = T;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            match self.0.get() {
+                Some(p) => p.0.parse_dyn(s),
+                None => ParseState::error_generic("recursive parser used before definition", s),
+            }
+        }
+    }
+
+    // RecursiveRef
+    //
+    // only holds a weak reference so the parser doesn't keep itself alive
+    pub struct RecursiveRef<'p, T>(Weak<OnceCell<BoxedParser<'p, T>>>);
+
+    impl<'p, T> Clone for RecursiveRef<'p, T> {
+        fn clone(&self) -> Self {
+            RecursiveRef(self.0.clone())
+        }
+    }
+
+    impl<'p, T> Parser for RecursiveRef<'p, T> {
+        type Output = T;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            match self.0.upgrade() {
+                Some(cell) => Recursive(cell).parse(s),
+                None => ParseState::error_generic("recursive parser used after being dropped", s),
+            }
+        }
+    }
+
+    ////////
+    ///
+    /// Expression
+    ///
+    ////////
+
+    struct PrefixOperator<'a, T> {
+        tag: &'a str,
+        precedence: u32,
+        f: Rc<dyn Fn(T) -> T + 'a>,
+    }
+
+    impl<'a, T> Clone for PrefixOperator<'a, T> {
+        fn clone(&self) -> Self {
+            PrefixOperator {
+                tag: self.tag,
+                precedence: self.precedence,
+                f: self.f.clone(),
+            }
+        }
+    }
+
+    struct InfixOperator<'a, T> {
+        tag: &'a str,
+        precedence: u32,
+        associativity: Associativity,
+        f: Rc<dyn Fn(T, T) -> T + 'a>,
+    }
+
+    impl<'a, T> Clone for InfixOperator<'a, T> {
+        fn clone(&self) -> Self {
+            InfixOperator {
+                tag: self.tag,
+                precedence: self.precedence,
+                associativity: self.associativity,
+                f: self.f.clone(),
+            }
+        }
+    }
+
+    // Expression
+    //
+    // precedence climbing over an atom parser, higher precedence binds tighter
+    pub struct Expression<'a, P, T> {
+        atom: P,
+        prefix: Vec<PrefixOperator<'a, T>>,
+        infix: Vec<InfixOperator<'a, T>>,
+        parens: Option<(&'a str, &'a str)>,
+        skip_whitespace: bool,
+    }
+
+    impl<'a, P, T> Expression<'a, P, T>
+    where
+        P: Parser<Output = T> + Clone,
+    {
+        pub fn new(atom: P) -> Self {
+            Expression {
+                atom,
+                prefix: Vec::new(),
+                infix: Vec::new(),
+                parens: None,
+                skip_whitespace: false,
+            }
+        }
+
+        pub fn prefix<F>(mut self, tag: &'a str, precedence: u32, f: F) -> Self
+        where
+            F: Fn(T) -> T + 'a,
+        {
+            self.prefix.push(PrefixOperator {
+                tag,
+                precedence,
+                f: Rc::new(f),
+            });
+            self
+        }
+
+        pub fn infix<F>(
+            mut self,
+            tag: &'a str,
+            precedence: u32,
+            associativity: Associativity,
+            f: F,
+        ) -> Self
+        where
+            F: Fn(T, T) -> T + 'a,
+        {
+            self.infix.push(InfixOperator {
+                tag,
+                precedence,
+                associativity,
+                f: Rc::new(f),
+            });
+            self
+        }
+
+        pub fn parenthesized(mut self, open: &'a str, close: &'a str) -> Self {
+            self.parens = Some((open, close));
+            self
+        }
+
+        pub fn skip_whitespace(mut self) -> Self {
+            self.skip_whitespace = true;
+            self
+        }
+
+        fn trim<'b>(&self, s: &'b str) -> &'b str {
+            if self.skip_whitespace {
+                s.trim_start_matches([' ', '\t'])
+            } else {
+                s
+            }
+        }
+
+        fn parse_operand<'b>(&self, s: &'b str) -> ParseState<'b, T> {
+            let s = self.trim(s);
+            if let Some(op) = self
+                .prefix
+                .iter()
+                .filter(|op| s.starts_with(op.tag))
+                .max_by_key(|op| op.tag.len())
+            {
+                let (operand, rest) = self.parse_expression(&s[op.tag.len()..], op.precedence)?;
+                return ParseState::ok((op.f)(operand), rest);
+            }
+            if let Some((inner, close)) = self
+                .parens
+                .and_then(|(open, close)| Some((s.strip_prefix(open)?, close)))
+            {
+                let (result, rest) = self.parse_expression(inner, 0)?;
+                return parsers::tag(close)
+                    .parse(self.trim(rest))
+                    .and_then(|_, rest| ParseState::ok(result, rest));
+            }
+            self.atom.clone().parse(s)
+        }
+
+        fn parse_expression<'b>(&self, s: &'b str, min_precedence: u32) -> ParseState<'b, T> {
+            let (mut lhs, mut rest) = self.parse_operand(s)?;
+            loop {
+                let trimmed = self.trim(rest);
+                // the longest tag wins even if it binds too loosely to continue,
+                // otherwise < could be read out of the front of <<
+                let Some(op) = self
+                    .infix
+                    .iter()
+                    .filter(|op| trimmed.starts_with(op.tag))
+                    .max_by_key(|op| op.tag.len())
+                    .filter(|op| op.precedence >= min_precedence)
+                else {
+                    return ParseState::ok(lhs, rest);
+                };
+                let operand = &trimmed[op.tag.len()..];
+                let (rhs, next_rest) = match op.associativity {
+                    Associativity::Left => match op.precedence.checked_add(1) {
+                        Some(next_precedence) => self.parse_expression(operand, next_precedence)?,
+                        // nothing binds tighter than the maximum precedence
+                        None => self.parse_operand(operand)?,
+                    },
+                    Associativity::Right => self.parse_expression(operand, op.precedence)?,
+                };
+                lhs = (op.f)(lhs, rhs);
+                rest = next_rest;
+            }
+        }
+    }
+
+    impl<'a, P: Clone, T> Clone for Expression<'a, P, T> {
+        fn clone(&self) -> Self {
+            Expression {
+                atom: self.atom.clone(),
+                prefix: self.prefix.clone(),
+                infix: self.infix.clone(),
+                parens: self.parens,
+                skip_whitespace: self.skip_whitespace,
+            }
+        }
+    }
+
+    impl<'b, P, T> Parser for Expression<'b, P, T>
+    where
+        P: Parser<Output = T> + Clone,
+    {
+        type Output = T;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            self.parse_expression(s, 0)
+        }
+    }
+
+    ////////
+    ///
+    /// Debugging
+    ///
+    ////////
+
+    // Trace
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct Trace<'a, P> {
+        name: &'a str,
+        p: P,
+    }
+
+    impl<'a, P> Trace<'a, P> {
+        pub fn new(name: &'a str, p: P) -> Self {
+            Trace { name, p }
+        }
+    }
+
+    impl<'b, T, P> Parser for Trace<'b, P>
+    where
+        P: Parser<Output = T>,
+    {
+        type Output = T;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            if !trace::is_enabled() {
+                return self.p.parse(s);
+            }
+            trace::enter(self.name, s);
+            let result = self.p.parse(s);
+            match &result {
+                ParseState::Ok { rest, .. } => trace::success(self.name, s.len() - rest.len()),
+                ParseState::Err { error, rest } => trace::failure(self.name, error, rest),
+            }
+            result
+        }
+    }
+
+    ////////
+    ///
+    /// Grid related
+    ///
+    ////////
+
+    // Grid
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct Grid<'a, 'b, P> {
+        seperator: &'a str,
+        terminator: &'b str,
+        p: P,
+    }
+
+    impl<'a, 'b, P> Grid<'a, 'b, P> {
+        pub fn new(seperator: &'a str, terminator: &'b str, p: P) -> Self {
+            Grid {
+                seperator,
+                terminator,
+                p,
+            }
+        }
+    }
+
+    impl<'b, 'c, T, P> Parser for Grid<'b, 'c, P>
+    where
+        P: Parser<Output = T> + Clone,
+        T: Clone,
+    {
+        type Output = grid::Grid<T>;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            let mut vec_of_vecs = Vec::new();
+            let (result, rest) = self
+                .p
+                .clone()
+                .list(self.seperator)
+                .skip_tag(self.terminator)
+                .parse(s)?;
+            vec_of_vecs.push(result.collect::<Vec<T>>());
+            let (result, rest) = self
+                .p
+                .clone()
+                .and_then(
+                    parsers::tag(self.seperator)
+                        .and_then(self.p.clone())
+                        .map(|(_, v)| v)
+                        .repeat((vec_of_vecs[0].len() - 1) as u32),
+                )
+                .map(|(head, mut tail): (T, ManyIter<T>)| {
+                    tail.cons(head);
+                    tail.collect::<Vec<T>>()
+                })
+                .many_lines(self.terminator)
+                .parse(rest)?;
+            vec_of_vecs.extend(result);
+            let cols = vec_of_vecs[0].len();
+            grid_of_rows(vec_of_vecs, cols, s, rest)
+        }
+    }
+
+    // GridWithSpecialCells
+    pub struct GridWithSpecialCells<'a, 'b, P> {
+        seperator: &'a str,
+        terminator: &'b str,
+        p: P,
+    }
+
+    impl<'a, 'b, P> GridWithSpecialCells<'a, 'b, P> {
+        pub fn new(seperator: &'a str, terminator: &'b str, p: P) -> Self {
+            GridWithSpecialCells {
+                seperator,
+                terminator,
+                p,
+            }
+        }
+    }
+
+    impl<'b, 'c, T, U, P> Parser for GridWithSpecialCells<'b, 'c, P>
+    where
+        P: Parser<Output = (T, Option<U>)> + Clone,
+        U: Eq + Hash + Clone,
+        T: Clone,
+    {
+        type Output = (grid::Grid<T>, HashMap<U, Vec<grid::GridPoint<usize>>>);
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            let mut vec_of_vecs = Vec::new();
+            let mut special_points = HashMap::new();
+            let (first_row, rest) = self
+                .p
+                .clone()
+                .list(self.seperator)
+                .skip_tag(self.terminator)
+                .parse(s)?;
+            first_row
+                .clone()
+                .enumerate()
+                .filter_map(|(idx, (_, b))| b.map(|b| (idx, b)))
+                .for_each(|(col, key)| {
+                    let point = grid::GridPoint::new(0, col);
+                    special_points
+                        .entry(key)
+                        .and_modify(|v: &mut Vec<grid::GridPoint<usize>>| v.push(point))
+                        .or_insert(vec![point]);
+                });
+            vec_of_vecs.push(first_row.map(|(a, _)| a).collect::<Vec<T>>());
+            let (result, rest) = self
+                .p
+                .clone()
+                .and_then(
+                    parsers::tag(self.seperator)
+                        .and_then(self.p.clone())
+                        .map(|(_, v)| v)
+                        .repeat((vec_of_vecs[0].len() - 1) as u32),
+                )
+                .map(|(head, mut tail)| {
+                    tail.cons(head);
+                    tail
+                })
+                .many_lines(self.terminator)
+                .parse(rest)?;
+            result.clone().enumerate().for_each(|(row_m1, row)| {
+                row.enumerate()
+                    .filter_map(|(col, (_, special))| special.map(|b| (col, b)))
+                    .for_each(|(col, key)| {
+                        let point = grid::GridPoint::new(1 + row_m1, col);
+                        special_points
+                            .entry(key)
+                            .and_modify(|v: &mut Vec<grid::GridPoint<usize>>| v.push(point))
+                            .or_insert(vec![point]);
+                    })
+            });
+            vec_of_vecs.extend(result.map(|i| i.map(|(cell, _)| cell).collect::<Vec<T>>()));
+            let cols = vec_of_vecs[0].len();
+            grid_of_rows(vec_of_vecs, cols, s, rest)
+                .and_then(|grid, rest| ParseState::ok((grid, special_points), rest))
+        }
+    }
+
+    // GridRows
+    //
+    // rows of cells of possibly different lengths, each paired with the length
+    // of the input remaining at the start of the row so errors can point at it
+    struct GridRows<'a, 'b, P> {
+        seperator: &'a str,
+        terminator: &'b str,
+        p: P,
+    }
+
+    impl<'b, 'c, T, P> Parser for GridRows<'b, 'c, P>
+    where
+        P: Parser<Output = T> + Clone,
+    {
+        type Output = Vec<(usize, Vec<T>)>;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            let mut rows = Vec::new();
+            let mut rest = s;
+            loop {
+                match self
+                    .p
+                    .clone()
+                    .list(self.seperator)
+                    .skip_tag(self.terminator)
+                    .parse(rest)
+                {
+                    ParseState::Ok { result, rest: next } => {
+                        rows.push((rest.len(), result.collect::<Vec<T>>()));
+                        rest = next;
+                    }
+                    ParseState::Err { error, rest } if rows.is_empty() => {
+                        return ParseState::error(error, rest)
+                    }
+                    ParseState::Err { .. } => return ParseState::ok(rows, rest),
+                }
+            }
+        }
+    }
+
+    fn grid_of_rows<'a, T: Clone>(
+        rows: Vec<Vec<T>>,
+        cols: usize,
+        s: &'a str,
+        rest: &'a str,
+    ) -> ParseState<'a, grid::Grid<T>> {
+        let row_count = rows.len();
+        match grid::Grid::of_list_of_lists(rows, row_count, cols) {
+            Some(grid) => ParseState::ok(grid, rest),
+            None => ParseState::error_generic("grid rows have inconsistent lengths", s),
+        }
+    }
+
+    // GridPadded
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct GridPadded<'a, 'b, P, T> {
+        seperator: &'a str,
+        terminator: &'b str,
+        p: P,
+        fill: T,
+    }
+
+    impl<'a, 'b, P, T> GridPadded<'a, 'b, P, T> {
+        pub fn new(seperator: &'a str, terminator: &'b str, p: P, fill: T) -> Self {
+            GridPadded {
+                seperator,
+                terminator,
+                p,
+                fill,
+            }
+        }
+    }
+
+    impl<'b, 'c, T, P> Parser for GridPadded<'b, 'c, P, T>
+    where
+        P: Parser<Output = T> + Clone,
+        T: Clone,
+    {
+        type Output = grid::Grid<T>;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            let (rows, rest) = GridRows {
+                seperator: self.seperator,
+                terminator: self.terminator,
+                p: self.p,
+            }
+            .parse(s)?;
+            let cols = rows.iter().map(|(_, row)| row.len()).max().unwrap_or(0);
+            let padded = rows
+                .into_iter()
+                .map(|(_, mut row)| {
+                    row.resize(cols, self.fill.clone());
+                    row
+                })
+                .collect();
+            grid_of_rows(padded, cols, s, rest)
+        }
+    }
+
+    // GridStrict
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct GridStrict<'a, 'b, P> {
+        seperator: &'a str,
+        terminator: &'b str,
+        p: P,
+    }
+
+    impl<'a, 'b, P> GridStrict<'a, 'b, P> {
+        pub fn new(seperator: &'a str, terminator: &'b str, p: P) -> Self {
+            GridStrict {
+                seperator,
+                terminator,
+                p,
+            }
+        }
+    }
+
+    impl<'b, 'c, T, P> Parser for GridStrict<'b, 'c, P>
+    where
+        P: Parser<Output = T> + Clone,
+        T: Clone,
+    {
+        type Output = grid::Grid<T>;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            let (rows, rest) = GridRows {
+                seperator: self.seperator,
+                terminator: self.terminator,
+                p: self.p,
+            }
+            .parse(s)?;
+            let expected = rows[0].1.len();
+            if let Some((row, (remaining, cells))) = rows
+                .iter()
+                .enumerate()
+                .find(|(_, (_, cells))| cells.len() != expected)
+            {
+                return ParseState::error(
+                    ParseError::RaggedRow {
+                        row,
+                        expected,
+                        found: cells.len(),
+                    },
+                    &s[s.len() - remaining..],
+                );
+            }
+            grid_of_rows(
+                rows.into_iter().map(|(_, cells)| cells).collect(),
+                expected,
+                s,
+                rest,
+            )
+        }
+    }
+
+    // LatticeGrid
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct LatticeGrid<'a, 'b, P> {
+        seperator: &'a str,
+        terminator: &'b str,
+        p: P,
+    }
+
+    impl<'a, 'b, P> LatticeGrid<'a, 'b, P> {
+        pub fn new(seperator: &'a str, terminator: &'b str, p: P) -> Self {
+            LatticeGrid {
+                seperator,
+                terminator,
+                p,
+            }
+        }
+    }
+
+    impl<'b, 'c, T, P> Parser for LatticeGrid<'b, 'c, P>
+    where
+        P: Parser<Output = Option<T>> + Clone,
+        T: Clone,
+    {
+        type Output = grid::Lattice<T>;
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            let (rows, rest) = GridRows {
+                seperator: self.seperator,
+                terminator: self.terminator,
+                p: self.p,
+            }
+            .parse(s)?;
+            ParseState::ok(
+                grid::Lattice::from(rows.into_iter().enumerate().flat_map(|(row, (_, cells))| {
+                    cells
+                        .into_iter()
+                        .enumerate()
+                        .filter_map(move |(col, cell)| {
+                            Some((grid::GridPoint::new(row as isize, col as isize), cell?))
+                        })
+                })),
+                rest,
+            )
+        }
+    }
+
+    // CharGrid
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    pub struct CharGrid<'a, T> {
+        mapping: &'a [(char, T)],
+        markers: Vec<(char, T)>,
+    }
+
+    impl<'a, T> CharGrid<'a, T> {
+        pub fn new(mapping: &'a [(char, T)]) -> Self {
+            CharGrid {
+                mapping,
+                markers: Vec::new(),
+            }
+        }
+
+        pub fn marker(mut self, c: char, cell: T) -> Self {
+            self.markers.push((c, cell));
+            self
+        }
+
+        fn cell(&self, c: char) -> Option<(&T, bool)> {
+            self.markers
+                .iter()
+                .find(|(marker, _)| *marker == c)
+                .map(|(_, cell)| (cell, true))
+                .or_else(|| {
+                    self.mapping
+                        .iter()
+                        .find(|(mapped, _)| *mapped == c)
+                        .map(|(_, cell)| (cell, false))
+                })
+        }
+    }
+
+    impl<'b, T> Parser for CharGrid<'b, T>
+    where
+        T: Clone,
+    {
+        type Output = (grid::Grid<T>, HashMap<char, Vec<grid::GridPoint<usize>>>);
+
+        fn parse<'a>(self, s: &'a str) -> ParseState<'a, Self::Output> {
+            let mut rows: Vec<Vec<T>> = Vec::new();
+            let mut markers: HashMap<char, Vec<grid::GridPoint<usize>>> = HashMap::new();
+            let mut rest = s;
+            while !rest.is_empty() && !rest.starts_with('\n') && !rest.starts_with("\r\n") {
+                let (line, next) = rest.split_once('\n').unwrap_or((rest, ""));
+                // tolerate CRLF line endings
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                let mut row = Vec::with_capacity(line.len());
+                for (col, (idx, c)) in line.char_indices().enumerate() {
+                    match self.cell(c) {
+                        None => {
+                            return ParseState::error(
+                                ParseError::UnmappedChar {
+                                    found: c,
+                                    row: rows.len(),
+                                    col,
+                                },
+                                &rest[idx..],
+                            )
+                        }
+                        Some((cell, is_marker)) => {
+                            if is_marker {
+                                markers
+                                    .entry(c)
+                                    .or_default()
+                                    .push(grid::GridPoint::new(rows.len(), col));
+                            }
+                            row.push(cell.clone());
+                        }
+                    }
+                }
+                if let Some(expected) = rows
+                    .first()
+                    .map(|first| first.len())
+                    .filter(|expected| *expected != row.len())
+                {
+                    return ParseState::error(
+                        ParseError::RaggedRow {
+                            row: rows.len(),
+                            expected,
+                            found: row.len(),
+                        },
+                        rest,
+                    );
+                }
+                rows.push(row);
+                rest = next;
+            }
+            if rows.is_empty() {
+                return match rest.chars().next() {
+                    None => ParseState::error_end_of_string(rest),
+                    Some(c) => ParseState::error_unexpected_char(c, rest),
+                };
+            }
+            let cols = rows[0].len();
+            grid_of_rows(rows, cols, s, rest)
+                .and_then(|grid, rest| ParseState::ok((grid, markers), rest))
+        }
+    }
+
+    ////////
+    ///
+    /// Printers
+    ///
+    ////////
+
+    impl Printer for Char {
+        fn print(&self, value: &Self::Output, out: &mut String) {
+            out.push(*value);
+        }
+    }
+
+    impl<F: Fn(char) -> bool> Printer for Chars<F> {
+        fn print(&self, value: &Self::Output, out: &mut String) {
+            out.push(*value);
+        }
+    }
+
+    impl<'b> Printer for Tag<'b> {
+        fn print(&self, _value: &Self::Output, out: &mut String) {
+            out.push_str(self.0);
+        }
+    }
+
+    impl<F: Fn(char) -> bool + Clone> Printer for ManyChars<F> {
+        fn print(&self, value: &Self::Output, out: &mut String) {
+            out.push_str(value);
+        }
+    }
+
+    impl<'b, T> Printer for Number<'b, T>
+    where
+        T: FromStr + Display,
+        <T as FromStr>::Err: 'static,
+    {
+        fn print(&self, value: &Self::Output, out: &mut String) {
+            out.push_str(&value.to_string());
+        }
+    }
+
+    impl<'b, T> Printer for SignedNumber<'b, T>
+    where
+        T: FromStr + Display,
+        <T as FromStr>::Err: 'static,
+    {
+        fn print(&self, value: &Self::Output, out: &mut String) {
+            out.push_str(&value.to_string());
+        }
+    }
+
+    impl<'a, T, U, P, Q> Printer for Pair<'a, P, Q>
+    where
+        P: Printer<Output = T>,
+        Q: Printer<Output = U>,
+    {
+        fn print(&self, value: &Self::Output, out: &mut String) {
+            self.p.print(&value.0, out);
+            out.push_str(self.sep);
+            self.q.print(&value.1, out);
+        }
+    }
+
+    impl<'a, T, P> Printer for List<'a, P>
+    where
+        P: Printer<Output = T> + Clone,
+    {
+        fn print(&self, value: &Self::Output, out: &mut String) {
+            for (idx, v) in value.contents.iter().enumerate() {
+                if idx > 0 {
+                    out.push_str(self.sep);
+                }
+                self.p.print(v, out);
+            }
+        }
+    }
+
+    impl<'b, T, P> Printer for Line<'b, P>
+    where
+        P: Printer<Output = T>,
+    {
+        fn print(&self, value: &Self::Output, out: &mut String) {
+            self.p.print(value, out);
+            out.push_str(self.terminator);
+        }
+    }
+
+    impl<'b, T, P> Printer for ManyLines<'b, P>
+    where
+        P: Printer<Output = T> + Clone,
+    {
+        fn print(&self, value: &Self::Output, out: &mut String) {
+            for v in value.contents.iter() {
+                self.p.print(v, out);
+                out.push_str(self.terminator);
+            }
+        }
+    }
+
+    impl<'b, 'c, T, P> Printer for Grid<'b, 'c, P>
+    where
+        P: Printer<Output = T> + Clone,
+        T: Clone,
+    {
+        fn print(&self, value: &Self::Output, out: &mut String) {
+            for row in 0..value.rows() {
+                for col in 0..value.cols() {
+                    if col > 0 {
+                        out.push_str(self.seperator);
+                    }
+                    self.p.print(&value[grid::GridPoint::new(row, col)], out);
+                }
+                out.push_str(self.terminator);
+            }
+        }
+    }
+}
 
 pub mod parsers {
 
-    use super::parsers_internal;
+    use super::{parsers_internal, Parser};
+    use crate::grid::{Direction, HexDirection, PointyHexDirection, Turn};
+    #[inline]
+    pub fn pure() -> parsers_internal::Pure {
+        parsers_internal::Pure::new()
+    }
+
+    #[inline]
+    pub fn fail<'a>(s: &'a str) -> parsers_internal::Fail<'a> {
+        parsers_internal::Fail::new(s)
+    }
+
+    #[inline]
+    pub fn chars<F: Fn(char) -> bool>(f: F) -> parsers_internal::Chars<F> {
+        parsers_internal::Chars::new(f)
+    }
+
+    #[inline]
+    pub fn char(c: char) -> parsers_internal::Char {
+        parsers_internal::Char::new(c)
+    }
+
+    #[inline]
+    pub fn char_any() -> parsers_internal::CharAny {
+        parsers_internal::CharAny::new()
+    }
+
+    #[inline]
+    pub fn char_map<T, F: Fn(char) -> Option<T>>(f: F) -> parsers_internal::CharMap<T, F> {
+        parsers_internal::CharMap::new(f)
+    }
+
+    #[inline]
+    pub fn direction_arrow() -> parsers_internal::CharMap<Direction, fn(char) -> Option<Direction>>
+    {
+        parsers_internal::CharMap::new(Direction::of_arrow)
+    }
+
+    #[inline]
+    pub fn direction_compass() -> parsers_internal::CharMap<Direction, fn(char) -> Option<Direction>>
+    {
+        parsers_internal::CharMap::new(Direction::of_compass)
+    }
+
+    #[inline]
+    pub fn direction_udlr() -> parsers_internal::CharMap<Direction, fn(char) -> Option<Direction>> {
+        parsers_internal::CharMap::new(Direction::of_udlr)
+    }
+
+    #[inline]
+    pub fn turn() -> parsers_internal::CharMap<Turn, fn(char) -> Option<Turn>> {
+        parsers_internal::CharMap::new(Turn::of_char)
+    }
+
+    #[inline]
+    pub fn hex_direction(
+    ) -> parsers_internal::StrMap<HexDirection, fn(&str) -> Option<HexDirection>> {
+        parsers_internal::StrMap::new(2, HexDirection::of_str)
+    }
+
+    #[inline]
+    pub fn pointy_hex_direction(
+    ) -> parsers_internal::StrMap<PointyHexDirection, fn(&str) -> Option<PointyHexDirection>> {
+        parsers_internal::StrMap::new(2, PointyHexDirection::of_str)
+    }
+
+    #[inline]
+    pub fn tag_replace<'a, T>(s: &'a str, t: T) -> parsers_internal::TagReplace<'a, T> {
+        parsers_internal::TagReplace::new(s, t)
+    }
+
+    #[inline]
+    pub fn tag<'a>(s: &'a str) -> parsers_internal::Tag<'a> {
+        parsers_internal::Tag::new(s)
+    }
+
+    #[inline]
+    pub fn any() -> parsers_internal::Any {
+        parsers_internal::Any
+    }
+
+    #[inline]
+    pub fn drop() -> parsers_internal::Drop {
+        parsers_internal::Drop
+    }
+
+    #[inline]
+    pub fn many_chars<F: Fn(char) -> bool + Clone>(f: F) -> parsers_internal::ManyChars<F> {
+        parsers_internal::ManyChars::new(f)
+    }
+
+    #[inline]
+    pub fn take_while1<F: Fn(char) -> bool + Clone>(f: F) -> parsers_internal::TakeWhile1<F> {
+        parsers_internal::TakeWhile1::new(f)
+    }
+
+    #[inline]
+    pub fn take_until<'a>(tag: &'a str) -> parsers_internal::TakeUntil<'a> {
+        parsers_internal::TakeUntil::new(tag)
+    }
+
+    #[inline]
+    pub fn not<P: Parser>(p: P) -> parsers_internal::Not<P> {
+        parsers_internal::Not::new(p)
+    }
+
+    #[inline]
+    pub fn peek<P: Parser>(p: P) -> parsers_internal::Peek<P> {
+        parsers_internal::Peek::new(p)
+    }
+
+    #[inline]
+    pub fn number<'a, T>() -> parsers_internal::Number<'a, T> {
+        parsers_internal::Number::new("")
+    }
+
     #[inline]
-    pub fn pure() -> parsers_internal::Pure {
-        parsers_internal::Pure::new()
+    pub fn number_with_seps<'a, T>(sep_chars: &'a str) -> parsers_internal::Number<'a, T> {
+        parsers_internal::Number::new(sep_chars)
     }
 
     #[inline]
-    pub fn fail<'a>(s: &'a str) -> parsers_internal::Fail<'a> {
-        parsers_internal::Fail::new(s)
+    pub fn signed_number<T>() -> parsers_internal::SignedNumber<'static, T> {
+        parsers_internal::SignedNumber::new("")
     }
 
     #[inline]
-    pub fn chars<F: Fn(char) -> bool>(f: F) -> parsers_internal::Chars<F> {
-        parsers_internal::Chars::new(f)
+    pub fn signed_number_with_seps<'a, T>(
+        sep_chars: &'a str,
+    ) -> parsers_internal::SignedNumber<'a, T> {
+        parsers_internal::SignedNumber::new(sep_chars)
     }
 
     #[inline]
-    pub fn char(c: char) -> parsers_internal::Char {
-        parsers_internal::Char::new(c)
+    pub fn number_with_radix<'a, T>(radix: u32) -> parsers_internal::RadixNumber<'a, T> {
+        parsers_internal::RadixNumber::new(radix, "")
     }
 
     #[inline]
-    pub fn char_any() -> parsers_internal::CharAny {
-        parsers_internal::CharAny::new()
+    pub fn hex_number<T>() -> parsers_internal::RadixNumber<'static, T> {
+        parsers_internal::RadixNumber::new(16, "0x")
     }
 
     #[inline]
-    pub fn char_map<T, F: Fn(char) -> Option<T>>(f: F) -> parsers_internal::CharMap<T, F> {
-        parsers_internal::CharMap::new(f)
+    pub fn octal_number<T>() -> parsers_internal::RadixNumber<'static, T> {
+        parsers_internal::RadixNumber::new(8, "0o")
     }
 
     #[inline]
-    pub fn tag_replace<'a, T>(s: &'a str, t: T) -> parsers_internal::TagReplace<'a, T> {
-        parsers_internal::TagReplace::new(s, t)
+    pub fn binary_number<T>() -> parsers_internal::RadixNumber<'static, T> {
+        parsers_internal::RadixNumber::new(2, "0b")
     }
 
     #[inline]
-    pub fn tag<'a>(s: &'a str) -> parsers_internal::Tag<'a> {
-        parsers_internal::Tag::new(s)
+    pub fn float<T>() -> parsers_internal::Float<T> {
+        parsers_internal::Float::new()
     }
 
     #[inline]
-    pub fn any() -> parsers_internal::Any {
-        parsers_internal::Any
+    pub fn decimal_fraction<T>() -> parsers_internal::DecimalFraction<T> {
+        parsers_internal::DecimalFraction::new()
     }
 
     #[inline]
-    pub fn drop() -> parsers_internal::Drop {
-        parsers_internal::Drop
+    pub fn char_grid<'a, T>(mapping: &'a [(char, T)]) -> parsers_internal::CharGrid<'a, T> {
+        parsers_internal::CharGrid::new(mapping)
     }
 
     #[inline]
-    pub fn many_chars<F: Fn(char) -> bool + Clone>(f: F) -> parsers_internal::ManyChars<F> {
-        parsers_internal::ManyChars::new(f)
+    pub fn recursive<'p, T, P, F>(f: F) -> parsers_internal::Recursive<'p, T>
+    where
+        P: Parser<Output = T> + Clone + 'p,
+        F: FnOnce(parsers_internal::RecursiveRef<'p, T>) -> P,
+    {
+        parsers_internal::Recursive::new(f)
     }
 
     #[inline]
-    pub fn number<'a, T>() -> parsers_internal::Number<'a, T> {
-        parsers_internal::Number::new("")
+    pub fn expression<'a, T, P>(atom: P) -> parsers_internal::Expression<'a, P, T>
+    where
+        P: Parser<Output = T> + Clone,
+    {
+        parsers_internal::Expression::new(atom)
     }
+}
 
-    #[inline]
-    pub fn number_with_seps<'a, T>(sep_chars: &'a str) -> parsers_internal::Number<'a, T> {
-        parsers_internal::Number::new(sep_chars)
+///////
+///
+/// Parser tracing
+///
+/// opt-in, per thread log of every parser labelled with `Parser::trace`,
+/// rendered as an indented tree of entries, successes and failures. positions
+/// are line:col within the outermost traced input, which carries over to later
+/// top level traces of the rest of that input
+///
+///////
+
+pub mod trace {
+    use std::cell::RefCell;
+
+    use super::ParseError;
+
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub enum Sink {
+        Collect,
+        Stderr,
     }
 
-    #[inline]
-    pub fn signed_number<T>() -> parsers_internal::SignedNumber<'static, T> {
-        parsers_internal::SignedNumber::new("")
+    struct Tracer {
+        sink: Sink,
+        depth: usize,
+        // address and copy of the outermost traced input, kept across sibling
+        // top level parses of the same input so line numbers keep counting
+        root: Option<(usize, String)>,
+        lines: Vec<String>,
     }
 
-    #[inline]
-    pub fn signed_number_with_seps<'a, T>(
-        sep_chars: &'a str,
-    ) -> parsers_internal::SignedNumber<'a, T> {
-        parsers_internal::SignedNumber::new(sep_chars)
+    thread_local! {
+        static TRACER: RefCell<Option<Tracer>> = const { RefCell::new(None) };
+    }
+
+    pub fn enable(sink: Sink) {
+        TRACER.with_borrow_mut(|tracer| {
+            *tracer = Some(Tracer {
+                sink,
+                depth: 0,
+                root: None,
+                lines: Vec::new(),
+            })
+        });
+    }
+
+    pub fn disable() -> String {
+        TRACER
+            .with_borrow_mut(|tracer| tracer.take())
+            .map(|tracer| tracer.lines.join("\n"))
+            .unwrap_or_default()
+    }
+
+    pub fn is_enabled() -> bool {
+        TRACER.with_borrow(|tracer| tracer.is_some())
+    }
+
+    impl Tracer {
+        // how far into the root s starts, if s ends where the root does
+        fn offset(&self, s: &str) -> Option<usize> {
+            let (start, root) = self.root.as_ref()?;
+            let ptr = s.as_ptr() as usize;
+            let offset = ptr.checked_sub(*start)?;
+            (offset + s.len() == root.len()).then_some(offset)
+        }
+
+        // a new top level input reuses the root only if it really is a suffix
+        // of it, the address alone could belong to an input that's since gone
+        fn set_root(&mut self, s: &str) {
+            let is_suffix = self.offset(s).is_some_and(|offset| {
+                self.root
+                    .as_ref()
+                    .is_some_and(|(_, root)| root.get(offset..) == Some(s))
+            });
+            if !is_suffix {
+                self.root = Some((s.as_ptr() as usize, s.to_owned()));
+            }
+        }
+
+        // 1-indexed line and column of s within the outermost traced input
+        fn position(&self, s: &str) -> String {
+            match (self.offset(s), &self.root) {
+                (Some(offset), Some((_, root))) => match root.get(..offset) {
+                    Some(consumed) => format!(
+                        "{}:{}",
+                        consumed.matches('\n').count() + 1,
+                        consumed.len() - consumed.rfind('\n').map_or(0, |idx| idx + 1) + 1
+                    ),
+                    None => "?".to_owned(),
+                },
+                _ => "?".to_owned(),
+            }
+        }
+
+        fn log(&mut self, line: String) {
+            let line = format!("{}{}", "  ".repeat(self.depth), line);
+            match self.sink {
+                Sink::Collect => self.lines.push(line),
+                Sink::Stderr => eprintln!("{}", line),
+            }
+        }
+    }
+
+    fn preview(s: &str) -> String {
+        s.lines().next().unwrap_or("").chars().take(16).collect()
+    }
+
+    pub(super) fn enter(name: &str, s: &str) {
+        TRACER.with_borrow_mut(|tracer| {
+            if let Some(tracer) = tracer {
+                if tracer.depth == 0 {
+                    tracer.set_root(s);
+                }
+                let line = format!("{} @ {} {:?}", name, tracer.position(s), preview(s));
+                tracer.log(line);
+                tracer.depth += 1;
+            }
+        });
+    }
+
+    pub(super) fn success(name: &str, consumed: usize) {
+        TRACER.with_borrow_mut(|tracer| {
+            if let Some(tracer) = tracer {
+                tracer.depth = tracer.depth.saturating_sub(1);
+                tracer.log(format!("{} ok, consumed {}", name, consumed));
+            }
+        });
+    }
+
+    pub(super) fn failure(name: &str, error: &ParseError, rest: &str) {
+        TRACER.with_borrow_mut(|tracer| {
+            if let Some(tracer) = tracer {
+                tracer.depth = tracer.depth.saturating_sub(1);
+                let line = format!(
+                    "{} failed with {:?} @ {}",
+                    name,
+                    error,
+                    tracer.position(rest)
+                );
+                tracer.log(line);
+            }
+        });
     }
 }
 
@@ -1232,6 +2718,11 @@ pub trait Parser: Sized {
         parsers_internal::Repeat::new(count, self)
     }
 
+    #[inline]
+    fn between(self, min: u32, max: u32) -> parsers_internal::Between<Self> {
+        parsers_internal::Between::new(min, max, self)
+    }
+
     #[inline]
     fn line<'a>(self, terminator: &'a str) -> parsers_internal::Line<'a, Self> {
         parsers_internal::Line::new(terminator, self)
@@ -1242,6 +2733,11 @@ pub trait Parser: Sized {
         parsers_internal::List::new(sep, self)
     }
 
+    #[inline]
+    fn list_trailing<'a>(self, sep: &'a str) -> parsers_internal::ListTrailing<'a, Self> {
+        parsers_internal::ListTrailing::new(sep, self)
+    }
+
     #[inline]
     fn pair<'a, Q>(self, sep: &'a str, q: Q) -> parsers_internal::Pair<'a, Self, Q> {
         parsers_internal::Pair::new(sep, self, q)
@@ -1262,6 +2758,11 @@ pub trait Parser: Sized {
         parsers_internal::ManyLines::new(terminator, self)
     }
 
+    #[inline]
+    fn many_lines_par<'a>(self, terminator: &'a str) -> parsers_internal::ManyLinesPar<'a, Self> {
+        parsers_internal::ManyLinesPar::new(terminator, self)
+    }
+
     #[inline]
     fn grid<'a, 'b>(
         self,
@@ -1280,17 +2781,115 @@ pub trait Parser: Sized {
         parsers_internal::GridWithSpecialCells::new(seperator, terminator, self)
     }
 
+    #[inline]
+    fn grid_padded<'a, 'b>(
+        self,
+        seperator: &'a str,
+        terminator: &'b str,
+        fill: Self::Output,
+    ) -> parsers_internal::GridPadded<'a, 'b, Self, Self::Output> {
+        parsers_internal::GridPadded::new(seperator, terminator, self, fill)
+    }
+
+    #[inline]
+    fn grid_strict<'a, 'b>(
+        self,
+        seperator: &'a str,
+        terminator: &'b str,
+    ) -> parsers_internal::GridStrict<'a, 'b, Self> {
+        parsers_internal::GridStrict::new(seperator, terminator, self)
+    }
+
+    #[inline]
+    fn lattice<'a, 'b>(
+        self,
+        seperator: &'a str,
+        terminator: &'b str,
+    ) -> parsers_internal::LatticeGrid<'a, 'b, Self> {
+        parsers_internal::LatticeGrid::new(seperator, terminator, self)
+    }
+
     #[inline]
     fn maybe(self) -> parsers_internal::Maybe<Self> {
         parsers_internal::Maybe::new(self)
     }
+
+    #[inline]
+    fn trace<'a>(self, name: &'a str) -> parsers_internal::Trace<'a, Self> {
+        parsers_internal::Trace::new(name, self)
+    }
+
+    #[inline]
+    fn boxed<'p>(self) -> parsers_internal::BoxedParser<'p, Self::Output>
+    where
+        Self: Clone + 'p,
+    {
+        parsers_internal::BoxedParser::new(self)
+    }
+}
+
+///////
+///
+/// Printers
+///
+/// the inverse of a parser, printing a value such that parsing it gives the value back
+///
+///////
+
+pub trait Printer: Parser {
+    fn print(&self, value: &Self::Output, out: &mut String);
+
+    fn print_to_string(&self, value: &Self::Output) -> String {
+        let mut out = String::new();
+        self.print(value, &mut out);
+        out
+    }
+}
+
+///////
+///
+/// Testing
+///
+/// helpers for solution crates to check their parsers, enabled by the
+/// testing feature
+///
+///////
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing {
+    use std::fmt::Debug;
+
+    use super::Printer;
+
+    pub fn assert_roundtrip<P, I>(p: P, values: I)
+    where
+        P: Printer + Clone,
+        P::Output: PartialEq + Debug,
+        I: IntoIterator<Item = P::Output>,
+    {
+        for value in values {
+            let printed = p.print_to_string(&value);
+            assert_eq!(
+                p.clone().parse(&printed).finish(),
+                Ok(value),
+                "failed to roundtrip {:?}",
+                printed
+            );
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
-    use crate::grid::Grid;
+    use crate::grid::{
+        Direction, Grid, GridPoint, HexDirection, HexPoint, Lattice, MazeCell, PointyHexDirection,
+        Turn,
+    };
+    use num::{rational::Ratio, BigInt};
+    use std::collections::HashMap;
 
+    use super::testing::assert_roundtrip;
     use super::*;
 
     #[test]
@@ -1326,6 +2925,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn direction() {
+        let expected = vec![
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ];
+        for (parser, input) in [
+            (parsers::direction_arrow(), "^>v<"),
+            (parsers::direction_compass(), "NESW"),
+            (parsers::direction_udlr(), "URDL"),
+        ] {
+            assert_eq!(
+                parser
+                    .many()
+                    .parse(input)
+                    .finish()
+                    .map(|v| v.collect::<Vec<Direction>>()),
+                Ok(expected.clone())
+            );
+        }
+        assert_eq!(
+            parsers::direction_compass().parse("L90").finish(),
+            Err((ParseError::UnexpectedChar('L'), "L90"))
+        );
+        assert_eq!(
+            parsers::direction_arrow().parse("x").finish(),
+            Err((ParseError::UnexpectedChar('x'), "x"))
+        );
+        assert_eq!(
+            parsers::turn()
+                .pair("", parsers::number::<u32>())
+                .many()
+                .parse("R10L5B2")
+                .finish()
+                .map(|v| v.collect::<Vec<(Turn, u32)>>()),
+            Ok(vec![(Turn::Right, 10), (Turn::Left, 5), (Turn::Back, 2)])
+        );
+    }
+
+    #[test]
+    fn hex_direction() {
+        // 2020 day 24, pointy topped tiles walked without separators
+        let walk = |path: &str| {
+            parsers::pointy_hex_direction()
+                .many()
+                .parse(path)
+                .finish()
+                .map(|v| v.fold(HexPoint::origin(), |point, step| point + step))
+                .ok()
+        };
+        assert_eq!(
+            walk("esew"),
+            Some(HexPoint::origin() + PointyHexDirection::SouthEast)
+        );
+        assert_eq!(walk("nwwswee"), Some(HexPoint::origin()));
+        assert_eq!(
+            parsers::pointy_hex_direction().parse("n"),
+            ParseState::Err {
+                error: ParseError::UnexpectedChar('n'),
+                rest: "n"
+            }
+        );
+        assert_eq!(
+            parsers::hex_direction()
+                .list(",")
+                .parse("se,n,nw")
+                .finish()
+                .map(|v| v.collect::<Vec<HexDirection>>()),
+            Ok(vec![
+                HexDirection::SouthEast,
+                HexDirection::North,
+                HexDirection::NorthWest
+            ])
+        );
+    }
+
     #[test]
     fn char_map() {
         assert_eq!(
@@ -1465,52 +3142,174 @@ mod tests {
     }
 
     #[test]
-    fn many_chars() {
+    fn many_chars() {
+        assert_eq!(
+            parsers::many_chars(|c: char| c.is_numeric())
+                .parse("12345")
+                .finish(),
+            Ok("12345".to_owned())
+        );
+        assert_eq!(
+            parsers::many_chars(|c: char| c.is_numeric())
+                .parse("12345 ab")
+                .finish(),
+            Err((ParseError::RemainingUnparsed, " ab"))
+        );
+    }
+
+    #[test]
+    fn signed_number() {
+        assert_eq!(parsers::signed_number().parse("12345").finish(), Ok(12345));
+        assert_eq!(
+            parsers::signed_number().parse("-12345").finish(),
+            Ok(-12345)
+        );
+        assert_eq!(parsers::signed_number().parse("+12345").finish(), Ok(12345));
+        assert_eq!(parsers::signed_number().parse("12345").finish(), Ok(12345));
+        assert_eq!(
+            parsers::signed_number_with_seps("-")
+                .parse("-12-345")
+                .finish(),
+            Ok(-12345)
+        );
+        assert_eq!(
+            parsers::signed_number().parse("12,345"),
+            ParseState::ok(12, ",345")
+        );
+    }
+
+    #[test]
+    fn number() {
+        assert_eq!(parsers::number().parse("12345").finish(), Ok(12345));
+        assert_eq!(
+            parsers::number_with_seps(",").parse("12,345").finish(),
+            Ok(12345)
+        );
+        assert_eq!(
+            parsers::number().parse("12,345"),
+            ParseState::ok(12, ",345")
+        );
+    }
+
+    #[test]
+    fn number_overflow() {
+        assert_eq!(parsers::number::<u8>().parse("255").finish(), Ok(255));
+        assert_eq!(
+            parsers::number::<u8>().parse("256").finish(),
+            Err((ParseError::Overflow("256".to_owned(), "u8"), "256"))
+        );
+        assert_eq!(
+            parsers::signed_number::<i8>().parse("-128").finish(),
+            Ok(-128)
+        );
+        assert_eq!(
+            parsers::signed_number::<i8>().parse("-129").finish(),
+            Err((ParseError::Overflow("-129".to_owned(), "i8"), "-129"))
+        );
+        assert_eq!(
+            parsers::number::<i64>()
+                .parse("99999999999999999999")
+                .finish(),
+            Err((
+                ParseError::Overflow("99999999999999999999".to_owned(), "i64"),
+                "99999999999999999999"
+            ))
+        );
+    }
+
+    #[test]
+    fn radix_number() {
+        assert_eq!(parsers::hex_number::<u32>().parse("0xff").finish(), Ok(255));
+        assert_eq!(parsers::hex_number::<u32>().parse("FF").finish(), Ok(255));
+        assert_eq!(
+            parsers::octal_number::<u32>().parse("0o17").finish(),
+            Ok(15)
+        );
+        assert_eq!(
+            parsers::binary_number::<u8>().parse("0b1010 rest"),
+            ParseState::ok(10, " rest")
+        );
+        assert_eq!(
+            parsers::number_with_radix::<u64>(36).parse("zz").finish(),
+            Ok(1295)
+        );
+        assert_eq!(
+            parsers::binary_number::<u8>().parse("100000000").finish(),
+            Err((
+                ParseError::Overflow("100000000".to_owned(), "u8"),
+                "100000000"
+            ))
+        );
+        assert_eq!(
+            parsers::hex_number::<u32>().parse("xyz"),
+            ParseState::Err {
+                error: ParseError::ParseIntError("".to_owned()),
+                rest: "xyz"
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be in 2..=36, got 37")]
+    fn radix_number_out_of_range() {
+        parsers::number_with_radix::<u64>(37);
+    }
+
+    #[test]
+    fn big_number() {
+        let big = "123456789012345678901234567890";
         assert_eq!(
-            parsers::many_chars(|c: char| c.is_numeric())
-                .parse("12345")
+            parsers::signed_number::<BigInt>()
+                .parse(&format!("-{}", big))
                 .finish(),
-            Ok("12345".to_owned())
+            Ok(-big.parse::<BigInt>().unwrap())
         );
         assert_eq!(
-            parsers::many_chars(|c: char| c.is_numeric())
-                .parse("12345 ab")
+            parsers::hex_number::<BigInt>()
+                .parse("0xffffffffffffffffffffffffffffffff")
                 .finish(),
-            Err((ParseError::RemainingUnparsed, " ab"))
+            Ok(BigInt::from(u128::MAX))
         );
     }
 
     #[test]
-    fn signed_number() {
-        assert_eq!(parsers::signed_number().parse("12345").finish(), Ok(12345));
+    fn float() {
+        assert_eq!(parsers::float::<f64>().parse("3.25").finish(), Ok(3.25));
+        assert_eq!(parsers::float::<f64>().parse("-0.5").finish(), Ok(-0.5));
+        assert_eq!(parsers::float::<f64>().parse("1.5e3").finish(), Ok(1500.0));
+        assert_eq!(parsers::float::<f32>().parse("+2E-1").finish(), Ok(0.2));
+        assert_eq!(parsers::float::<f64>().parse("7"), ParseState::ok(7.0, ""));
         assert_eq!(
-            parsers::signed_number().parse("-12345").finish(),
-            Ok(-12345)
+            parsers::float::<f64>().parse("1..5"),
+            ParseState::ok(1.0, "..5")
         );
-        assert_eq!(parsers::signed_number().parse("+12345").finish(), Ok(12345));
-        assert_eq!(parsers::signed_number().parse("12345").finish(), Ok(12345));
         assert_eq!(
-            parsers::signed_number_with_seps("-")
-                .parse("-12-345")
-                .finish(),
-            Ok(-12345)
+            parsers::float::<f64>().parse("3east"),
+            ParseState::ok(3.0, "east")
         );
         assert_eq!(
-            parsers::signed_number().parse("12,345"),
-            ParseState::ok(12, ",345")
+            parsers::float::<f64>().parse("-x").finish(),
+            Err((ParseError::ParseFloatError("-".to_owned()), "-x"))
         );
     }
 
     #[test]
-    fn number() {
-        assert_eq!(parsers::number().parse("12345").finish(), Ok(12345));
+    fn decimal_fraction() {
         assert_eq!(
-            parsers::number_with_seps(",").parse("12,345").finish(),
-            Ok(12345)
+            parsers::decimal_fraction::<i64>().parse("12.375").finish(),
+            Ok(Ratio::new(99, 8))
         );
         assert_eq!(
-            parsers::number().parse("12,345"),
-            ParseState::ok(12, ",345")
+            parsers::decimal_fraction::<i64>().parse("-0.1").finish(),
+            Ok(Ratio::new(-1, 10))
+        );
+        assert_eq!(
+            parsers::decimal_fraction::<i64>().parse("42").finish(),
+            Ok(Ratio::from_integer(42))
+        );
+        assert_eq!(
+            parsers::decimal_fraction::<i8>().parse("0.001").finish(),
+            Err((ParseError::Overflow("1000".to_owned(), "i8"), "0.001"))
         );
     }
 
@@ -1621,6 +3420,66 @@ bjgGqQGbQnjGQgnQgbGgjJnDLHLdfPVtdDmLZdBFVVZttdTf
         );
     }
 
+    #[test]
+    fn many_lines_par() {
+        let input = (0..10_000)
+            .map(|n| format!("{},{}\n", n, n * 2))
+            .collect::<String>();
+        assert_eq!(
+            parsers::number::<u64>()
+                .pair(",", parsers::number::<u64>())
+                .many_lines_par("\n")
+                .parse(&input)
+                .finish()
+                .map(|v| v.collect::<Vec<(u64, u64)>>()),
+            Ok((0..10_000).map(|n| (n, n * 2)).collect())
+        );
+        assert_eq!(
+            parsers::number::<u32>()
+                .many_lines_par("\n")
+                .map(|v| v.collect::<Vec<u32>>())
+                .parse("1\n2\n3"),
+            ParseState::ok(vec![1, 2], "3")
+        );
+
+        let input = input.replace("5000,10000\n", "5000;10000\n");
+        assert_eq!(
+            parsers::number::<u64>()
+                .pair(",", parsers::number::<u64>())
+                .many_lines_par("\n")
+                .parse(&input)
+                .finish()
+                .map(|v| v.count()),
+            Err((
+                ParseError::AtLine {
+                    line: 5001,
+                    error: Box::new(ParseError::UnmatchedTag(",".to_owned()))
+                },
+                &input[input.find(";10000").unwrap()..]
+            ))
+        );
+        let bad_line = input[..input.find(";10000").unwrap()].matches('\n').count() + 1;
+        assert_eq!(bad_line, 5001);
+        assert_eq!(input.lines().nth(bad_line - 1), Some("5000;10000"));
+
+        // any() hands back a rest that isn't a subslice of the line
+        assert_eq!(
+            parsers::any()
+                .and_then(parsers::tag("x"))
+                .many_lines_par("\n")
+                .parse("ab\ncd\n")
+                .finish()
+                .map(|v| v.count()),
+            Err((
+                ParseError::AtLine {
+                    line: 1,
+                    error: Box::new(ParseError::UnmatchedTag("x".to_owned()))
+                },
+                "ab\ncd\n"
+            ))
+        );
+    }
+
     #[test]
     fn grid() {
         assert_eq!(
@@ -1649,6 +3508,13 @@ bjgGqQGbQnjGQgnQgbGgjJnDLHLdfPVtdDmLZdBFVVZttdTf
                 .finish(),
             Err((ParseError::RemainingUnparsed, "defg\nhi\n"))
         );
+        assert_eq!(
+            parsers::chars(|c| c.is_alphabetic())
+                .grid("", "\n")
+                .parse("abc\nde\nfgh\n")
+                .finish(),
+            Err((ParseError::RemainingUnparsed, "de\nfgh\n"))
+        );
 
         assert_eq!(
             parsers::chars(|c| c.is_alphabetic())
@@ -1682,6 +3548,413 @@ bjgGqQGbQnjGQgnQgbGgjJnDLHLdfPVtdDmLZdBFVVZttdTf
         );
     }
 
+    #[test]
+    fn grid_padded() {
+        assert_eq!(
+            parsers::chars(|c| c != '\n')
+                .grid_padded("", "\n", ' ')
+                .parse("  .#\n....\n#.\n")
+                .finish(),
+            Ok(Grid::from(
+                vec![' ', ' ', '.', '#', '.', '.', '.', '.', '#', '.', ' ', ' '],
+                3,
+                4
+            )
+            .unwrap())
+        );
+        assert_eq!(
+            parsers::number::<u32>()
+                .grid_padded(",", "\n", 0)
+                .parse("1,2\n3\n\nrest"),
+            ParseState::ok(Grid::from(vec![1, 2, 3, 0], 2, 2).unwrap(), "\nrest")
+        );
+        assert_eq!(
+            parsers::chars(|c| c.is_alphabetic())
+                .grid_padded("", "\n", ' ')
+                .parse("")
+                .finish(),
+            Err((ParseError::EndOfString, ""))
+        );
+    }
+
+    #[test]
+    fn grid_strict() {
+        assert_eq!(
+            parsers::chars(|c| c.is_alphabetic())
+                .grid_strict("", "\n")
+                .parse("abc\ndef\n")
+                .finish(),
+            Ok(Grid::from(vec!['a', 'b', 'c', 'd', 'e', 'f'], 2, 3).unwrap())
+        );
+        assert_eq!(
+            parsers::chars(|c| c.is_alphabetic())
+                .grid_strict("", "\n")
+                .parse("abc\ndef\ngh\nijk\n")
+                .finish(),
+            Err((
+                ParseError::RaggedRow {
+                    row: 2,
+                    expected: 3,
+                    found: 2
+                },
+                "gh\nijk\n"
+            ))
+        );
+    }
+
+    #[test]
+    fn lattice() {
+        let lattice = parsers::char('#')
+            .map(|_| Some(()))
+            .or(parsers::char('.').map(|_| None))
+            .lattice("", "\n")
+            .parse(".#\n#\n..#\n")
+            .finish()
+            .unwrap();
+        assert_eq!(
+            lattice,
+            Lattice::from(vec![
+                (GridPoint::new(0, 1), ()),
+                (GridPoint::new(1, 0), ()),
+                (GridPoint::new(2, 2), ())
+            ])
+        );
+    }
+
+    #[test]
+    fn char_grid() {
+        let mapping = [('#', MazeCell::Wall), ('.', MazeCell::Empty)];
+        let (grid, markers) = parsers::char_grid(&mapping)
+            .marker('S', MazeCell::Empty)
+            .marker('E', MazeCell::Empty)
+            .parse("#S.\n#.#\n..E\n")
+            .finish()
+            .unwrap();
+        assert_eq!(
+            grid,
+            Grid::from(
+                vec![
+                    MazeCell::Wall,
+                    MazeCell::Empty,
+                    MazeCell::Empty,
+                    MazeCell::Wall,
+                    MazeCell::Empty,
+                    MazeCell::Wall,
+                    MazeCell::Empty,
+                    MazeCell::Empty,
+                    MazeCell::Empty
+                ],
+                3,
+                3
+            )
+            .unwrap()
+        );
+        assert_eq!(markers[&'S'], vec![GridPoint::new(0, 1)]);
+        assert_eq!(markers[&'E'], vec![GridPoint::new(2, 2)]);
+
+        assert_eq!(
+            parsers::char_grid(&[('#', true), ('.', false)]).parse("#.\n.#\n\nrest"),
+            ParseState::ok(
+                (
+                    Grid::from(vec![true, false, false, true], 2, 2).unwrap(),
+                    HashMap::new()
+                ),
+                "\nrest"
+            )
+        );
+        assert_eq!(
+            parsers::char_grid(&[('#', true), ('.', false)]).parse("#.\r\n.#\r\n\r\nrest"),
+            ParseState::ok(
+                (
+                    Grid::from(vec![true, false, false, true], 2, 2).unwrap(),
+                    HashMap::new()
+                ),
+                "\r\nrest"
+            )
+        );
+        assert_eq!(
+            parsers::char_grid(&[('#', true), ('.', false)])
+                .parse("#.")
+                .finish()
+                .map(|(grid, _)| grid),
+            Ok(Grid::from(vec![true, false], 1, 2).unwrap())
+        );
+        assert_eq!(
+            parsers::char_grid(&mapping).parse("#.#\n.x.\n").finish(),
+            Err((
+                ParseError::UnmappedChar {
+                    found: 'x',
+                    row: 1,
+                    col: 1
+                },
+                "x.\n"
+            ))
+        );
+        assert_eq!(
+            parsers::char_grid(&mapping).parse("#.#\n..\n").finish(),
+            Err((
+                ParseError::RaggedRow {
+                    row: 1,
+                    expected: 3,
+                    found: 2
+                },
+                "..\n"
+            ))
+        );
+    }
+
+    #[test]
+    fn between() {
+        let digits = || {
+            parsers::chars(|c| c.is_ascii_digit())
+                .between(2, 4)
+                .map(|v| v.collect::<String>())
+        };
+        assert_eq!(
+            digits().parse("12345"),
+            ParseState::ok("1234".to_owned(), "5")
+        );
+        assert_eq!(digits().parse("12a"), ParseState::ok("12".to_owned(), "a"));
+        assert_eq!(
+            digits().parse("1a").finish(),
+            Err((ParseError::UnexpectedChar('a'), "a"))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "between needs min <= max, got 4 and 2")]
+    fn between_inverted_bounds() {
+        parsers::chars(|c| c.is_ascii_digit()).between(4, 2);
+    }
+
+    #[test]
+    fn list_trailing() {
+        assert_eq!(
+            parsers::number::<u32>()
+                .list_trailing(",")
+                .parse("1,2,3,")
+                .finish()
+                .map(|v| v.collect::<Vec<u32>>()),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(
+            parsers::number::<u32>()
+                .list_trailing(",")
+                .parse("1,2,3")
+                .finish()
+                .map(|v| v.collect::<Vec<u32>>()),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(
+            parsers::number::<u32>()
+                .list_trailing(", ")
+                .parse("1, 2,,")
+                .finish()
+                .map(|v| v.collect::<Vec<u32>>()),
+            Err((ParseError::RemainingUnparsed, ",,"))
+        );
+    }
+
+    #[test]
+    fn take_until() {
+        assert_eq!(
+            parsers::take_until(" -> ").parse("abc def -> xyz"),
+            ParseState::ok("abc def".to_owned(), " -> xyz")
+        );
+        assert_eq!(
+            parsers::take_until("]").parse("]"),
+            ParseState::ok("".to_owned(), "]")
+        );
+        assert_eq!(
+            parsers::take_until("]").parse("abc").finish(),
+            Err((ParseError::UnmatchedTag("]".to_owned()), "abc"))
+        );
+    }
+
+    #[test]
+    fn take_while1() {
+        assert_eq!(
+            parsers::take_while1(|c| c.is_alphabetic()).parse("abc1"),
+            ParseState::ok("abc".to_owned(), "1")
+        );
+        assert_eq!(
+            parsers::take_while1(|c| c.is_alphabetic()).parse("1abc"),
+            ParseState::Err {
+                error: ParseError::UnexpectedChar('1'),
+                rest: "1abc"
+            }
+        );
+    }
+
+    #[test]
+    fn not() {
+        let identifier = parsers::not(parsers::tag("end"))
+            .ignore_and_then(parsers::take_while1(|c| c.is_alphabetic()));
+        assert_eq!(identifier.parse("abc").finish(), Ok("abc".to_owned()));
+        assert_eq!(
+            identifier.parse("end").finish(),
+            Err((ParseError::UnexpectedMatch, "end"))
+        );
+    }
+
+    #[test]
+    fn peek() {
+        assert_eq!(
+            parsers::peek(parsers::number::<u32>()).parse("123"),
+            ParseState::ok(123, "123")
+        );
+        assert_eq!(
+            parsers::peek(parsers::char('a'))
+                .and_then(parsers::any())
+                .parse("abc")
+                .finish(),
+            Ok(('a', "abc".to_owned()))
+        );
+        assert_eq!(
+            parsers::peek(parsers::char('a')).parse("b").finish(),
+            Err((ParseError::UnexpectedChar('b'), "b"))
+        );
+    }
+
+    #[test]
+    fn trace() {
+        let record = parsers::number::<u32>()
+            .trace("number")
+            .pair(
+                " ",
+                parsers::take_while1(|c| c.is_alphabetic()).trace("word"),
+            )
+            .trace("record");
+        trace::enable(trace::Sink::Collect);
+        let result = record
+            .many_lines("\n")
+            .trace("lines")
+            .parse("1 ab\n2 3\n")
+            .finish();
+        let log = trace::disable();
+        assert_eq!(
+            result.map(|v| v.count()),
+            Err((ParseError::RemainingUnparsed, "2 3\n"))
+        );
+        assert_eq!(
+            log,
+            [
+                "lines @ 1:1 \"1 ab\"",
+                "  record @ 1:1 \"1 ab\"",
+                "    number @ 1:1 \"1 ab\"",
+                "    number ok, consumed 1",
+                "    word @ 1:3 \"ab\"",
+                "    word ok, consumed 2",
+                "  record ok, consumed 4",
+                "  record @ 2:1 \"2 3\"",
+                "    number @ 2:1 \"2 3\"",
+                "    number ok, consumed 1",
+                "    word @ 2:3 \"3\"",
+                "    word failed with UnexpectedChar('3') @ 2:3",
+                "  record failed with UnexpectedChar('3') @ 2:3",
+                "lines ok, consumed 5",
+            ]
+            .join("\n")
+        );
+
+        // sibling top level traces of the same input keep counting lines
+        trace::enable(trace::Sink::Collect);
+        let _ = record.many_lines("\n").parse("1 ab\n2 3\n");
+        let log = trace::disable();
+        assert!(log.contains("\nrecord @ 2:1 \"2 3\""));
+        assert!(log.ends_with("record failed with UnexpectedChar('3') @ 2:3"));
+
+        // a different input at the same depth starts a new root
+        trace::enable(trace::Sink::Collect);
+        let first = String::from("1 ab\n2 cd\n");
+        let _ = record.many_lines("\n").parse(&first);
+        let second = String::from("3 ");
+        let _ = record.parse(&second);
+        let log = trace::disable();
+        assert!(log.ends_with("record failed with EndOfString @ 1:3"));
+
+        assert!(!trace::is_enabled());
+        assert_eq!(record.parse("4 cd").finish(), Ok((4, "cd".to_owned())));
+        assert_eq!(trace::disable(), "");
+    }
+
+    // xorshift, so roundtrip values are generated but reproducible
+    fn generated(seed: u64, count: usize) -> Vec<u64> {
+        let mut state = seed;
+        (0..count)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state
+            })
+            .collect()
+    }
+
+    #[test]
+    fn roundtrip() {
+        assert_roundtrip(parsers::number::<u64>(), generated(1, 100));
+        assert_roundtrip(
+            parsers::signed_number::<i64>(),
+            generated(2, 100).into_iter().map(|v| v as i64),
+        );
+        assert_roundtrip(parsers::tag("->"), ["->"]);
+        assert_roundtrip(
+            parsers::number::<u64>().pair(
+                " -> ",
+                parsers::many_chars(|c: char| c.is_ascii_lowercase()),
+            ),
+            generated(3, 100).into_iter().map(|v| {
+                let word = (0..1 + v % 8)
+                    .map(|idx| (b'a' + ((v >> (idx * 5)) % 26) as u8) as char)
+                    .collect::<String>();
+                (v, word)
+            }),
+        );
+        assert_roundtrip(
+            parsers::number::<u64>().list(",").many_lines("\n"),
+            (0..20).map(|seed| {
+                generated(seed + 4, (seed % 5) as usize)
+                    .into_iter()
+                    .map(|len| generated(len, 1 + (len % 6) as usize).into_iter().collect())
+                    .collect()
+            }),
+        );
+        assert_roundtrip(
+            parsers::number::<u32>().list("\n").list("\n\n"),
+            (0..20).map(|seed| {
+                generated(seed + 30, 1 + (seed % 4) as usize)
+                    .into_iter()
+                    .map(|len| {
+                        generated(len, 1 + (len % 3) as usize)
+                            .into_iter()
+                            .map(|v| v as u32)
+                            .collect()
+                    })
+                    .collect()
+            }),
+        );
+        assert_roundtrip(
+            parsers::number::<u64>().grid(" ", "\n"),
+            (1..10).map(|size| {
+                Grid::from(generated(size, (size * 3) as usize), size as usize, 3).unwrap()
+            }),
+        );
+        assert_roundtrip(
+            parsers::chars(|c| c == '#' || c == '.').grid("", "\n"),
+            (1..10).map(|size| {
+                Grid::from(
+                    generated(size + 50, (size * size) as usize)
+                        .into_iter()
+                        .map(|v| if v % 2 == 0 { '#' } else { '.' }),
+                    size as usize,
+                    size as usize,
+                )
+                .unwrap()
+            }),
+        );
+    }
+
     #[test]
     fn list_of_lists() {
         let input = "123
@@ -1927,4 +4200,130 @@ bjgGqQGbQnjGQgnQgbGgjJnDLHLdfPVtdDmLZdBFVVZttdTf
             Err((ParseError::RemainingUnparsed, "f"))
         );
     }
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    enum Nested {
+        Value(u32),
+        List(Vec<Nested>),
+    }
+
+    #[test]
+    fn recursive() {
+        let nested = || {
+            parsers::recursive(|nested| {
+                parsers::number().map(Nested::Value).or(parsers::char('[')
+                    .ignore_and_then(nested.list(",").maybe())
+                    .skip(parsers::char(']'))
+                    .map(|l| Nested::List(l.map(|l| l.collect()).unwrap_or_default())))
+            })
+        };
+        assert_eq!(
+            nested().parse("[1,[2,[3]],[]]").finish(),
+            Ok(Nested::List(vec![
+                Nested::Value(1),
+                Nested::List(vec![Nested::Value(2), Nested::List(vec![Nested::Value(3)])]),
+                Nested::List(vec![])
+            ]))
+        );
+        assert_eq!(nested().parse("7").finish(), Ok(Nested::Value(7)));
+        assert_eq!(
+            nested().parse("[1,[2]").finish(),
+            Err((ParseError::EndOfString, ""))
+        );
+
+        let expression = parsers::recursive(|expression| {
+            let term = parsers::number::<u64>().or(parsers::char('(')
+                .ignore_and_then(expression)
+                .skip(parsers::char(')')));
+            term.clone()
+                .and_then(parsers::char('+').ignore_and_then(term).many())
+                .map(|(head, tail)| head + tail.sum::<u64>())
+        });
+        assert_eq!(expression.clone().parse("1+(2+3)+4").finish(), Ok(10));
+        assert_eq!(expression.parse("((1+2)+(3))").finish(), Ok(6));
+    }
+
+    #[test]
+    fn boxed() {
+        let parsers = vec![
+            parsers::char('a').boxed(),
+            parsers::char('b').or(parsers::char('c')).boxed(),
+        ];
+        assert_eq!(
+            parsers
+                .into_iter()
+                .map(|p| p.parse("c").finish())
+                .collect::<Vec<_>>(),
+            vec![Err((ParseError::UnexpectedChar('c'), "c")), Ok('c')]
+        );
+    }
+
+    #[test]
+    fn expression() {
+        let arithmetic = parsers::expression(parsers::number::<i64>())
+            .prefix("-", 3, |a| -a)
+            .infix("+", 1, Associativity::Left, |a, b| a + b)
+            .infix("-", 1, Associativity::Left, |a, b| a - b)
+            .infix("*", 2, Associativity::Left, |a, b| a * b)
+            .infix("**", 4, Associativity::Right, |a, b| a.pow(b as u32))
+            .parenthesized("(", ")");
+        assert_eq!(arithmetic.clone().parse("1+2*3").finish(), Ok(7));
+        assert_eq!(arithmetic.clone().parse("(1+2)*3").finish(), Ok(9));
+        assert_eq!(arithmetic.clone().parse("10-4-3").finish(), Ok(3));
+        assert_eq!(arithmetic.clone().parse("2**3**2").finish(), Ok(512));
+        assert_eq!(arithmetic.clone().parse("-2*-(3-5)").finish(), Ok(-4));
+        assert_eq!(arithmetic.clone().parse("1+2)"), ParseState::ok(3, ")"));
+        assert_eq!(
+            arithmetic.clone().parse("1+").finish(),
+            Err((ParseError::ParseIntError("".to_owned()), ""))
+        );
+        assert_eq!(
+            arithmetic.parse("(1+2").finish(),
+            Err((ParseError::UnmatchedTag(")".to_owned()), ""))
+        );
+
+        let max_precedence = parsers::expression(parsers::number::<i64>())
+            .infix("-", u32::MAX, Associativity::Left, |a, b| a - b)
+            .infix("+", 0, Associativity::Left, |a, b| a + b);
+        assert_eq!(max_precedence.parse("10-4-3+1").finish(), Ok(4));
+
+        let shifts = parsers::expression(parsers::number::<u64>())
+            .infix("<<", 1, Associativity::Left, |a, b| a << b)
+            .infix("<", 3, Associativity::Left, |a, b| (a < b) as u64)
+            .infix("+", 2, Associativity::Left, |a, b| a + b);
+        assert_eq!(shifts.clone().parse("1+1<<2").finish(), Ok(8));
+        assert_eq!(shifts.clone().parse("1<2<<3").finish(), Ok(8));
+        assert_eq!(shifts.parse("2<<1+1").finish(), Ok(8));
+
+        let addition_first = parsers::expression(parsers::number::<u64>())
+            .infix("+", 2, Associativity::Left, |a, b| a + b)
+            .infix("*", 1, Associativity::Left, |a, b| a * b)
+            .parenthesized("(", ")")
+            .skip_whitespace();
+        assert_eq!(
+            addition_first.clone().parse("2 * 3 + (4 * 5)").finish(),
+            Ok(46)
+        );
+        assert_eq!(
+            addition_first
+                .many_lines("\n")
+                .parse("1 + 2 * 3 + 4 * 5 + 6\n1 + (2 * 3) + (4 * (5 + 6))\n")
+                .finish()
+                .map(|v| v.collect::<Vec<u64>>()),
+            Ok(vec![231, 51])
+        );
+
+        let ast = parsers::expression(parsers::many_chars(|c| c.is_alphabetic()))
+            .prefix("!", 2, |a| format!("(!{})", a))
+            .infix("&", 1, Associativity::Left, |a, b| {
+                format!("({} & {})", a, b)
+            })
+            .infix("|", 0, Associativity::Left, |a, b| {
+                format!("({} | {})", a, b)
+            });
+        assert_eq!(
+            ast.parse("a|!b&c|d").finish(),
+            Ok("((a | ((!b) & c)) | d)".to_owned())
+        );
+    }
 }